@@ -0,0 +1,105 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseCommandPermission {
+    #[iden = "command_permissions"]
+    Table,
+    GuildId,
+    Command,
+    RoleId,
+    /// Bits of Discord permissions
+    Permission,
+}
+
+/// Requirement for members to run commands that manage `command` in a guild, where either of `role_id` or
+/// `permission` is enough
+#[derive(Debug, Clone, FromRow)]
+pub struct CommandPermission {
+    pub guild_id: i64,
+    pub command: String,
+    pub role_id: Option<i64>,
+    pub permission: Option<i64>,
+}
+
+const COLUMNS: [DatabaseCommandPermission; 4] = [
+    DatabaseCommandPermission::GuildId,
+    DatabaseCommandPermission::Command,
+    DatabaseCommandPermission::RoleId,
+    DatabaseCommandPermission::Permission,
+];
+
+pub async fn create(
+    database: &PgPool,
+    guild_id: u64,
+    command: impl Into<String>,
+    role_id: Option<u64>,
+    permission: Option<u64>,
+) -> Result<CommandPermission> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseCommandPermission::Table)
+        .columns(COLUMNS)
+        .values_panic([
+            guild_id.into(),
+            command.into().into(),
+            role_id.into(),
+            permission.map(|permission| permission as i64).into(),
+        ])
+        .on_conflict(
+            OnConflict::columns([DatabaseCommandPermission::GuildId, DatabaseCommandPermission::Command])
+                .update_columns([DatabaseCommandPermission::RoleId, DatabaseCommandPermission::Permission])
+                .to_owned(),
+        )
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, CommandPermission, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, guild_id: u64, command: &str) -> Result<Option<CommandPermission>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseCommandPermission::Table)
+        .and_where(Expr::col(DatabaseCommandPermission::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseCommandPermission::Command).eq(command))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, CommandPermission, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch(database: &PgPool, guild_id: u64, command: &str) -> Result<Option<CommandPermission>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseCommandPermission::Table)
+        .and_where(Expr::col(DatabaseCommandPermission::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseCommandPermission::Command).eq(command))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, CommandPermission, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_by_guild_id(database: &PgPool, guild_id: u64) -> Result<Vec<CommandPermission>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseCommandPermission::Table)
+        .and_where(Expr::col(DatabaseCommandPermission::GuildId).eq(guild_id))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, CommandPermission, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
@@ -0,0 +1,178 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseDictionaryHistory {
+    #[iden = "dictionary_histories"]
+    Table,
+    Id,
+    GuildId,
+    UserId,
+    Surface,
+    Operation,
+    BeforePronunciation,
+    BeforeAccentType,
+    BeforeWordType,
+    BeforePriority,
+    AfterPronunciation,
+    AfterAccentType,
+    AfterWordType,
+    AfterPriority,
+    /// Unix time in seconds when the change is made
+    CreatedAt,
+}
+
+/// Change of a word in the dictionary of VOICEVOX, where `before` and `after` are absent for addition and deletion
+/// respectively.
+#[derive(Debug, Clone, FromRow)]
+pub struct DictionaryHistory {
+    pub id: i64,
+    pub guild_id: i64,
+    pub user_id: i64,
+    pub surface: String,
+    pub operation: String,
+    pub before_pronunciation: Option<String>,
+    pub before_accent_type: Option<i32>,
+    pub before_word_type: Option<String>,
+    pub before_priority: Option<i32>,
+    pub after_pronunciation: Option<String>,
+    pub after_accent_type: Option<i32>,
+    pub after_word_type: Option<String>,
+    pub after_priority: Option<i32>,
+    pub created_at: i64,
+}
+
+/// Version of a word in the dictionary of VOICEVOX
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictionaryWord {
+    pub pronunciation: String,
+    pub accent_type: i32,
+    pub word_type: Option<String>,
+    pub priority: i32,
+}
+
+impl DictionaryHistory {
+    pub fn before(&self) -> Option<DictionaryWord> {
+        Some(DictionaryWord {
+            pronunciation: self.before_pronunciation.clone()?,
+            accent_type: self.before_accent_type?,
+            word_type: self.before_word_type.clone(),
+            priority: self.before_priority?,
+        })
+    }
+
+    pub fn after(&self) -> Option<DictionaryWord> {
+        Some(DictionaryWord {
+            pronunciation: self.after_pronunciation.clone()?,
+            accent_type: self.after_accent_type?,
+            word_type: self.after_word_type.clone(),
+            priority: self.after_priority?,
+        })
+    }
+}
+
+const COLUMNS: [DatabaseDictionaryHistory; 14] = [
+    DatabaseDictionaryHistory::Id,
+    DatabaseDictionaryHistory::GuildId,
+    DatabaseDictionaryHistory::UserId,
+    DatabaseDictionaryHistory::Surface,
+    DatabaseDictionaryHistory::Operation,
+    DatabaseDictionaryHistory::BeforePronunciation,
+    DatabaseDictionaryHistory::BeforeAccentType,
+    DatabaseDictionaryHistory::BeforeWordType,
+    DatabaseDictionaryHistory::BeforePriority,
+    DatabaseDictionaryHistory::AfterPronunciation,
+    DatabaseDictionaryHistory::AfterAccentType,
+    DatabaseDictionaryHistory::AfterWordType,
+    DatabaseDictionaryHistory::AfterPriority,
+    DatabaseDictionaryHistory::CreatedAt,
+];
+
+pub async fn create(
+    database: &PgPool,
+    guild_id: u64,
+    user_id: u64,
+    surface: impl Into<String>,
+    operation: impl Into<String>,
+    before: Option<&DictionaryWord>,
+    after: Option<&DictionaryWord>,
+) -> Result<DictionaryHistory> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseDictionaryHistory::Table)
+        .columns([
+            DatabaseDictionaryHistory::GuildId,
+            DatabaseDictionaryHistory::UserId,
+            DatabaseDictionaryHistory::Surface,
+            DatabaseDictionaryHistory::Operation,
+            DatabaseDictionaryHistory::BeforePronunciation,
+            DatabaseDictionaryHistory::BeforeAccentType,
+            DatabaseDictionaryHistory::BeforeWordType,
+            DatabaseDictionaryHistory::BeforePriority,
+            DatabaseDictionaryHistory::AfterPronunciation,
+            DatabaseDictionaryHistory::AfterAccentType,
+            DatabaseDictionaryHistory::AfterWordType,
+            DatabaseDictionaryHistory::AfterPriority,
+        ])
+        .values_panic([
+            guild_id.into(),
+            user_id.into(),
+            surface.into().into(),
+            operation.into().into(),
+            before.map(|word| word.pronunciation.clone()).into(),
+            before.map(|word| word.accent_type).into(),
+            before.and_then(|word| word.word_type.clone()).into(),
+            before.map(|word| word.priority).into(),
+            after.map(|word| word.pronunciation.clone()).into(),
+            after.map(|word| word.accent_type).into(),
+            after.and_then(|word| word.word_type.clone()).into(),
+            after.map(|word| word.priority).into(),
+        ])
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, DictionaryHistory, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+/// Fetches a change made in `guild_id`. Changes are recorded per guild though the dictionary is shared across guilds.
+pub async fn fetch_by_id(database: &PgPool, guild_id: u64, id: i64) -> Result<Option<DictionaryHistory>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseDictionaryHistory::Table)
+        .and_where(Expr::col(DatabaseDictionaryHistory::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseDictionaryHistory::Id).eq(id))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, DictionaryHistory, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+/// Fetches the latest `limit` changes of `surface` made in `guild_id` from newest to oldest.
+pub async fn fetch_by_surface(
+    database: &PgPool,
+    guild_id: u64,
+    surface: &str,
+    limit: u64,
+) -> Result<Vec<DictionaryHistory>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseDictionaryHistory::Table)
+        .and_where(Expr::col(DatabaseDictionaryHistory::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseDictionaryHistory::Surface).eq(surface))
+        .order_by(DatabaseDictionaryHistory::Id, Order::Desc)
+        .limit(limit)
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, DictionaryHistory, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
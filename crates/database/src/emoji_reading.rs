@@ -0,0 +1,84 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseEmojiReading {
+    #[iden = "emoji_readings"]
+    Table,
+    GuildId,
+    EmojiId,
+    Name,
+    Reading,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct EmojiReading {
+    pub guild_id: i64,
+    pub emoji_id: i64,
+    pub name: String,
+    pub reading: String,
+}
+
+const COLUMNS: [DatabaseEmojiReading; 4] = [
+    DatabaseEmojiReading::GuildId,
+    DatabaseEmojiReading::EmojiId,
+    DatabaseEmojiReading::Name,
+    DatabaseEmojiReading::Reading,
+];
+
+pub async fn create(
+    database: &PgPool,
+    guild_id: u64,
+    emoji_id: u64,
+    name: impl Into<String>,
+    reading: impl Into<String>,
+) -> Result<EmojiReading> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseEmojiReading::Table)
+        .columns(COLUMNS)
+        .values_panic([guild_id.into(), emoji_id.into(), name.into().into(), reading.into().into()])
+        .on_conflict(
+            OnConflict::columns([DatabaseEmojiReading::GuildId, DatabaseEmojiReading::EmojiId])
+                .update_columns([DatabaseEmojiReading::Name, DatabaseEmojiReading::Reading])
+                .to_owned(),
+        )
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, EmojiReading, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, guild_id: u64, emoji_id: u64) -> Result<Option<EmojiReading>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseEmojiReading::Table)
+        .and_where(Expr::col(DatabaseEmojiReading::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseEmojiReading::EmojiId).eq(emoji_id))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, EmojiReading, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_by_guild_id(database: &PgPool, guild_id: u64) -> Result<Vec<EmojiReading>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseEmojiReading::Table)
+        .and_where(Expr::col(DatabaseEmojiReading::GuildId).eq(guild_id))
+        .order_by(DatabaseEmojiReading::Name, Order::Asc)
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, EmojiReading, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
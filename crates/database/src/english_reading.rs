@@ -0,0 +1,85 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseEnglishReading {
+    #[iden = "english_readings"]
+    Table,
+    Word,
+    Reading,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct EnglishReading {
+    pub word: String,
+    pub reading: String,
+}
+
+const COLUMNS: [DatabaseEnglishReading; 2] = [DatabaseEnglishReading::Word, DatabaseEnglishReading::Reading];
+
+pub async fn create(database: &PgPool, word: impl Into<String>, reading: impl Into<String>) -> Result<EnglishReading> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseEnglishReading::Table)
+        .columns(COLUMNS)
+        .values_panic([word.into().into(), reading.into().into()])
+        .on_conflict(
+            OnConflict::column(DatabaseEnglishReading::Word)
+                .update_column(DatabaseEnglishReading::Reading)
+                .to_owned(),
+        )
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, EnglishReading, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, word: &str) -> Result<Option<EnglishReading>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseEnglishReading::Table)
+        .and_where(Expr::col(DatabaseEnglishReading::Word).eq(word))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, EnglishReading, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_all(database: &PgPool) -> Result<Vec<EnglishReading>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseEnglishReading::Table)
+        .order_by(DatabaseEnglishReading::Word, Order::Asc)
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, EnglishReading, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_by_words(database: &PgPool, words: &[&str]) -> Result<Vec<EnglishReading>> {
+    if words.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseEnglishReading::Table)
+        .and_where(Expr::col(DatabaseEnglishReading::Word).is_in(words.iter().copied()))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, EnglishReading, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
@@ -0,0 +1,180 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, PostgresQueryBuilder, Query, SimpleExpr};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseGuild {
+    #[iden = "guilds"]
+    Table,
+    Id,
+    Volume,
+    Bitrate,
+    ReadSticker,
+    ReadEmbed,
+    ReadPoll,
+    ReadForward,
+    ReadReply,
+    ReadFilename,
+    AnnounceName,
+    UrlMode,
+    CollapseEmoji,
+    ReadQuote,
+    ExpandSlang,
+}
+
+/// Boolean settings of a guild
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flag {
+    ReadSticker,
+    ReadEmbed,
+    ReadPoll,
+    ReadForward,
+    ReadReply,
+    ReadFilename,
+    AnnounceName,
+    CollapseEmoji,
+    ReadQuote,
+    ExpandSlang,
+}
+
+impl From<Flag> for DatabaseGuild {
+    fn from(flag: Flag) -> Self {
+        match flag {
+            Flag::ReadSticker => DatabaseGuild::ReadSticker,
+            Flag::ReadEmbed => DatabaseGuild::ReadEmbed,
+            Flag::ReadPoll => DatabaseGuild::ReadPoll,
+            Flag::ReadForward => DatabaseGuild::ReadForward,
+            Flag::ReadReply => DatabaseGuild::ReadReply,
+            Flag::ReadFilename => DatabaseGuild::ReadFilename,
+            Flag::AnnounceName => DatabaseGuild::AnnounceName,
+            Flag::CollapseEmoji => DatabaseGuild::CollapseEmoji,
+            Flag::ReadQuote => DatabaseGuild::ReadQuote,
+            Flag::ExpandSlang => DatabaseGuild::ExpandSlang,
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct Guild {
+    pub id: i64,
+    pub volume: f32,
+    pub bitrate: Option<i32>,
+    pub read_sticker: bool,
+    pub read_embed: bool,
+    pub read_poll: bool,
+    pub read_forward: bool,
+    pub read_reply: bool,
+    pub read_filename: bool,
+    pub announce_name: bool,
+    pub url_mode: String,
+    pub collapse_emoji: bool,
+    pub read_quote: bool,
+    pub expand_slang: bool,
+}
+
+impl Guild {
+    pub fn flag(&self, flag: Flag) -> bool {
+        match flag {
+            Flag::ReadSticker => self.read_sticker,
+            Flag::ReadEmbed => self.read_embed,
+            Flag::ReadPoll => self.read_poll,
+            Flag::ReadForward => self.read_forward,
+            Flag::ReadReply => self.read_reply,
+            Flag::ReadFilename => self.read_filename,
+            Flag::AnnounceName => self.announce_name,
+            Flag::CollapseEmoji => self.collapse_emoji,
+            Flag::ReadQuote => self.read_quote,
+            Flag::ExpandSlang => self.expand_slang,
+        }
+    }
+}
+
+impl Default for Guild {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            volume: 1.0,
+            bitrate: None,
+            read_sticker: true,
+            read_embed: true,
+            read_poll: true,
+            read_forward: true,
+            read_reply: true,
+            read_filename: false,
+            announce_name: false,
+            url_mode: "url".to_string(),
+            collapse_emoji: false,
+            read_quote: true,
+            expand_slang: true,
+        }
+    }
+}
+
+const COLUMNS: [DatabaseGuild; 14] = [
+    DatabaseGuild::Id,
+    DatabaseGuild::Volume,
+    DatabaseGuild::Bitrate,
+    DatabaseGuild::ReadSticker,
+    DatabaseGuild::ReadEmbed,
+    DatabaseGuild::ReadPoll,
+    DatabaseGuild::ReadForward,
+    DatabaseGuild::ReadReply,
+    DatabaseGuild::ReadFilename,
+    DatabaseGuild::AnnounceName,
+    DatabaseGuild::UrlMode,
+    DatabaseGuild::CollapseEmoji,
+    DatabaseGuild::ReadQuote,
+    DatabaseGuild::ExpandSlang,
+];
+
+pub async fn fetch_by_id(database: &PgPool, id: u64) -> Result<Guild> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseGuild::Table)
+        .and_where(Expr::col(DatabaseGuild::Id).eq(id))
+        .build_sqlx(PostgresQueryBuilder);
+
+    let guilds: Vec<Guild> = sqlx::query_as_with::<_, Guild, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)?;
+
+    Ok(guilds.into_iter().next().unwrap_or(Guild {
+        id: id as i64,
+        ..Default::default()
+    }))
+}
+
+pub async fn update_volume(database: &PgPool, id: u64, volume: f32) -> Result<Guild> {
+    upsert(database, id, DatabaseGuild::Volume, volume.into()).await
+}
+
+pub async fn update_bitrate(database: &PgPool, id: u64, bitrate: Option<u32>) -> Result<Guild> {
+    upsert(database, id, DatabaseGuild::Bitrate, bitrate.map(|bitrate| bitrate as i32).into()).await
+}
+
+pub async fn update_url_mode(database: &PgPool, id: u64, url_mode: &str) -> Result<Guild> {
+    upsert(database, id, DatabaseGuild::UrlMode, url_mode.into()).await
+}
+
+pub async fn update_flag(database: &PgPool, id: u64, flag: Flag, enabled: bool) -> Result<Guild> {
+    upsert(database, id, flag.into(), enabled.into()).await
+}
+
+async fn upsert(database: &PgPool, id: u64, column: DatabaseGuild, value: SimpleExpr) -> Result<Guild> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseGuild::Table)
+        .columns([DatabaseGuild::Id, column])
+        .values_panic([id.into(), value])
+        .on_conflict(OnConflict::column(DatabaseGuild::Id).update_column(column).to_owned())
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Guild, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
@@ -0,0 +1,75 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseIgnore {
+    #[iden = "ignores"]
+    Table,
+    GuildId,
+    /// One of `user`, `role` and `channel`
+    Kind,
+    TargetId,
+}
+
+/// User, role or channel whose messages are not read in a guild
+#[derive(Debug, Clone, FromRow)]
+pub struct Ignore {
+    pub guild_id: i64,
+    pub kind: String,
+    pub target_id: i64,
+}
+
+const COLUMNS: [DatabaseIgnore; 3] = [DatabaseIgnore::GuildId, DatabaseIgnore::Kind, DatabaseIgnore::TargetId];
+
+/// Adds `target_id` to the ignored ones, where `None` is returned if it has already been ignored.
+pub async fn create(database: &PgPool, guild_id: u64, kind: impl Into<String>, target_id: u64) -> Result<Option<Ignore>> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseIgnore::Table)
+        .columns(COLUMNS)
+        .values_panic([guild_id.into(), kind.into().into(), target_id.into()])
+        .on_conflict(
+            OnConflict::columns(COLUMNS)
+                .do_nothing()
+                .to_owned(),
+        )
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Ignore, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, guild_id: u64, kind: &str, target_id: u64) -> Result<Option<Ignore>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseIgnore::Table)
+        .and_where(Expr::col(DatabaseIgnore::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseIgnore::Kind).eq(kind))
+        .and_where(Expr::col(DatabaseIgnore::TargetId).eq(target_id))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Ignore, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_by_guild_id(database: &PgPool, guild_id: u64) -> Result<Vec<Ignore>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseIgnore::Table)
+        .and_where(Expr::col(DatabaseIgnore::GuildId).eq(guild_id))
+        .order_by(DatabaseIgnore::Kind, Order::Asc)
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Ignore, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
@@ -4,9 +4,21 @@ pub use sqlx::{
     PgPool,
 };
 
+pub mod command_permission;
+pub mod dictionary_history;
+pub mod emoji_reading;
+pub mod english_reading;
+pub mod guild;
+pub mod ignore;
 pub mod migrations;
+pub mod ng_word;
+pub mod nickname;
+pub mod readable_bot;
+pub mod replacement_rule;
+pub mod slang_override;
 pub mod sound;
 pub mod soundsticker;
 pub mod speaker;
 pub mod sticker;
+pub mod transliteration;
 pub mod user;
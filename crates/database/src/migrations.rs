@@ -7,6 +7,26 @@ pub use sqlx_migrator::MigrationCommand;
 
 pub mod v1_users_and_speakers;
 pub mod v2_soundstickers;
+pub mod v3_guilds;
+pub mod v4_guild_bitrate;
+pub mod v5_guild_reading;
+pub mod v6_guild_read_filename;
+pub mod v7_guild_announce_name;
+pub mod v8_nicknames;
+pub mod v9_replacement_rules;
+pub mod v10_guild_url_mode;
+pub mod v11_emoji_readings;
+pub mod v12_guild_collapse_emoji;
+pub mod v13_guild_read_quote;
+pub mod v14_guild_expand_slang;
+pub mod v15_transliterations;
+pub mod v16_english_readings;
+pub mod v17_user_read_romaji;
+pub mod v18_dictionary_histories;
+pub mod v19_command_permissions;
+pub mod v20_ignores;
+pub mod v21_ng_words;
+pub mod v22_slang_overrides;
 
 pub struct Migrator {
     inner: migrator::Migrator<Postgres>,
@@ -32,6 +52,26 @@ impl Migrator {
         migrator.add_migrations(vec_box!(
             v1_users_and_speakers::V1Migration,
             v2_soundstickers::V2Migration,
+            v3_guilds::V3Migration,
+            v4_guild_bitrate::V4Migration,
+            v5_guild_reading::V5Migration,
+            v6_guild_read_filename::V6Migration,
+            v7_guild_announce_name::V7Migration,
+            v8_nicknames::V8Migration,
+            v9_replacement_rules::V9Migration,
+            v10_guild_url_mode::V10Migration,
+            v11_emoji_readings::V11Migration,
+            v12_guild_collapse_emoji::V12Migration,
+            v13_guild_read_quote::V13Migration,
+            v14_guild_expand_slang::V14Migration,
+            v15_transliterations::V15Migration,
+            v16_english_readings::V16Migration,
+            v17_user_read_romaji::V17Migration,
+            v18_dictionary_histories::V18Migration,
+            v19_command_permissions::V19Migration,
+            v20_ignores::V20Migration,
+            v21_ng_words::V21Migration,
+            v22_slang_overrides::V22Migration,
         )).expect("failed to add migrations to migrator");
 
         Self { inner: migrator }
@@ -0,0 +1,136 @@
+use futures::future::BoxFuture;
+use sea_query::{ColumnDef, Expr, Index, PostgresQueryBuilder, Table};
+use sqlx::{PgConnection, Postgres};
+use sqlx_migrator::{operation::Operation, vec_box};
+
+use crate::dictionary_history::DatabaseDictionaryHistory;
+
+pub(crate) struct CreateTableOperation;
+pub(crate) struct CreateIndexOperation;
+
+pub(crate) struct V18Migration;
+
+impl Operation<Postgres> for CreateTableOperation {
+    fn up<'a, 'b, 'async_trait>(
+        &'a self,
+        connection: &'b mut PgConnection,
+    ) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::create()
+                .if_not_exists()
+                .table(DatabaseDictionaryHistory::Table)
+                .col(
+                    ColumnDef::new(DatabaseDictionaryHistory::Id)
+                        .big_integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(DatabaseDictionaryHistory::GuildId).big_integer().not_null())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::UserId).big_integer().not_null())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::Surface).text().not_null())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::Operation).text().not_null())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::BeforePronunciation).text())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::BeforeAccentType).integer())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::BeforeWordType).text())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::BeforePriority).integer())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::AfterPronunciation).text())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::AfterAccentType).integer())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::AfterWordType).text())
+                .col(ColumnDef::new(DatabaseDictionaryHistory::AfterPriority).integer())
+                .col(
+                    ColumnDef::new(DatabaseDictionaryHistory::CreatedAt)
+                        .big_integer()
+                        .not_null()
+                        .default(Expr::cust("extract(epoch from now())::bigint")),
+                )
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(
+        &'a self,
+        connection: &'b mut PgConnection,
+    ) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::drop()
+                .table(DatabaseDictionaryHistory::Table)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+impl Operation<Postgres> for CreateIndexOperation {
+    fn up<'a, 'b, 'async_trait>(
+        &'a self,
+        connection: &'b mut PgConnection,
+    ) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Index::create()
+                .if_not_exists()
+                .name("dictionary_histories_surface_idx")
+                .table(DatabaseDictionaryHistory::Table)
+                .col(DatabaseDictionaryHistory::Surface)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(
+        &'a self,
+        connection: &'b mut PgConnection,
+    ) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Index::drop()
+                .name("dictionary_histories_surface_idx")
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+sqlx_migrator::migration!(
+    sqlx::Postgres,
+    V18Migration,
+    "seitai",
+    "create dictionary_histories",
+    vec_box![],
+    vec_box![
+        CreateTableOperation,
+        CreateIndexOperation,
+    ]
+);
@@ -0,0 +1,63 @@
+use futures::future::BoxFuture;
+use sea_query::{ColumnDef, Index, PostgresQueryBuilder, Table};
+use sqlx::{PgConnection, Postgres};
+use sqlx_migrator::{operation::Operation, vec_box};
+
+use crate::command_permission::DatabaseCommandPermission;
+
+pub(crate) struct CreateTableOperation;
+
+pub(crate) struct V19Migration;
+
+impl Operation<Postgres> for CreateTableOperation {
+    fn up<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::create()
+                .if_not_exists()
+                .table(DatabaseCommandPermission::Table)
+                .col(ColumnDef::new(DatabaseCommandPermission::GuildId).big_integer().not_null())
+                .col(ColumnDef::new(DatabaseCommandPermission::Command).text().not_null())
+                .col(ColumnDef::new(DatabaseCommandPermission::RoleId).big_integer())
+                .col(ColumnDef::new(DatabaseCommandPermission::Permission).big_integer())
+                .primary_key(Index::create().col(DatabaseCommandPermission::GuildId).col(DatabaseCommandPermission::Command))
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::drop()
+                .table(DatabaseCommandPermission::Table)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+sqlx_migrator::migration!(
+    sqlx::Postgres,
+    V19Migration,
+    "seitai",
+    "create command_permissions",
+    vec_box![],
+    vec_box![
+        CreateTableOperation,
+    ]
+);
@@ -0,0 +1,109 @@
+use futures::future::BoxFuture;
+use sea_query::{ColumnDef, Index, PostgresQueryBuilder, Table};
+use sqlx::{PgConnection, Postgres};
+use sqlx_migrator::{operation::Operation, vec_box};
+
+use crate::{ignore::DatabaseIgnore, readable_bot::DatabaseReadableBot};
+
+pub(crate) struct CreateIgnoresTableOperation;
+pub(crate) struct CreateReadableBotsTableOperation;
+
+pub(crate) struct V20Migration;
+
+impl Operation<Postgres> for CreateIgnoresTableOperation {
+    fn up<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::create()
+                .if_not_exists()
+                .table(DatabaseIgnore::Table)
+                .col(ColumnDef::new(DatabaseIgnore::GuildId).big_integer().not_null())
+                .col(ColumnDef::new(DatabaseIgnore::Kind).text().not_null())
+                .col(ColumnDef::new(DatabaseIgnore::TargetId).big_integer().not_null())
+                .primary_key(
+                    Index::create()
+                        .col(DatabaseIgnore::GuildId)
+                        .col(DatabaseIgnore::Kind)
+                        .col(DatabaseIgnore::TargetId),
+                )
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::drop()
+                .table(DatabaseIgnore::Table)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+impl Operation<Postgres> for CreateReadableBotsTableOperation {
+    fn up<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::create()
+                .if_not_exists()
+                .table(DatabaseReadableBot::Table)
+                .col(ColumnDef::new(DatabaseReadableBot::GuildId).big_integer().not_null())
+                .col(ColumnDef::new(DatabaseReadableBot::BotId).big_integer().not_null())
+                .primary_key(Index::create().col(DatabaseReadableBot::GuildId).col(DatabaseReadableBot::BotId))
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::drop()
+                .table(DatabaseReadableBot::Table)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+sqlx_migrator::migration!(
+    sqlx::Postgres,
+    V20Migration,
+    "seitai",
+    "create ignores and readable_bots",
+    vec_box![],
+    vec_box![
+        CreateIgnoresTableOperation,
+        CreateReadableBotsTableOperation,
+    ]
+);
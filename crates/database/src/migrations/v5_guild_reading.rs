@@ -0,0 +1,67 @@
+use futures::future::BoxFuture;
+use sea_query::{ColumnDef, PostgresQueryBuilder, Table};
+use sqlx::{PgConnection, Postgres};
+use sqlx_migrator::{operation::Operation, vec_box};
+
+use crate::guild::DatabaseGuild;
+
+pub(crate) struct AddColumnsOperation;
+
+pub(crate) struct V5Migration;
+
+impl Operation<Postgres> for AddColumnsOperation {
+    fn up<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::alter()
+                .table(DatabaseGuild::Table)
+                .add_column_if_not_exists(ColumnDef::new(DatabaseGuild::ReadSticker).boolean().not_null().default(true))
+                .add_column_if_not_exists(ColumnDef::new(DatabaseGuild::ReadEmbed).boolean().not_null().default(true))
+                .add_column_if_not_exists(ColumnDef::new(DatabaseGuild::ReadPoll).boolean().not_null().default(true))
+                .add_column_if_not_exists(ColumnDef::new(DatabaseGuild::ReadForward).boolean().not_null().default(true))
+                .add_column_if_not_exists(ColumnDef::new(DatabaseGuild::ReadReply).boolean().not_null().default(true))
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::alter()
+                .table(DatabaseGuild::Table)
+                .drop_column(DatabaseGuild::ReadSticker)
+                .drop_column(DatabaseGuild::ReadEmbed)
+                .drop_column(DatabaseGuild::ReadPoll)
+                .drop_column(DatabaseGuild::ReadForward)
+                .drop_column(DatabaseGuild::ReadReply)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+sqlx_migrator::migration!(
+    sqlx::Postgres,
+    V5Migration,
+    "seitai",
+    "add reading settings to guilds",
+    vec_box![],
+    vec_box![
+        AddColumnsOperation,
+    ]
+);
@@ -0,0 +1,62 @@
+use futures::future::BoxFuture;
+use sea_query::{ColumnDef, Index, PostgresQueryBuilder, Table};
+use sqlx::{PgConnection, Postgres};
+use sqlx_migrator::{operation::Operation, vec_box};
+
+use crate::nickname::DatabaseNickname;
+
+pub(crate) struct CreateTableOperation;
+
+pub(crate) struct V8Migration;
+
+impl Operation<Postgres> for CreateTableOperation {
+    fn up<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::create()
+                .if_not_exists()
+                .table(DatabaseNickname::Table)
+                .col(ColumnDef::new(DatabaseNickname::UserId).big_integer().not_null())
+                .col(ColumnDef::new(DatabaseNickname::GuildId).big_integer().not_null())
+                .col(ColumnDef::new(DatabaseNickname::Reading).text().not_null())
+                .primary_key(Index::create().col(DatabaseNickname::UserId).col(DatabaseNickname::GuildId))
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::drop()
+                .table(DatabaseNickname::Table)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+sqlx_migrator::migration!(
+    sqlx::Postgres,
+    V8Migration,
+    "seitai",
+    "create nicknames",
+    vec_box![],
+    vec_box![
+        CreateTableOperation,
+    ]
+);
@@ -0,0 +1,106 @@
+use futures::future::BoxFuture;
+use sea_query::{ColumnDef, Index, PgFunc, PostgresQueryBuilder, Table};
+use sqlx::{PgConnection, Postgres};
+use sqlx_migrator::{operation::Operation, vec_box};
+
+use crate::replacement_rule::DatabaseReplacementRule;
+
+pub(crate) struct CreateTableOperation;
+pub(crate) struct CreateIndexOperation;
+
+pub(crate) struct V9Migration;
+
+impl Operation<Postgres> for CreateTableOperation {
+    fn up<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::create()
+                .if_not_exists()
+                .table(DatabaseReplacementRule::Table)
+                .col(ColumnDef::new(DatabaseReplacementRule::Id).uuid().default(PgFunc::gen_random_uuid()).primary_key())
+                .col(ColumnDef::new(DatabaseReplacementRule::GuildId).big_integer().not_null())
+                .col(ColumnDef::new(DatabaseReplacementRule::Position).integer().not_null())
+                .col(ColumnDef::new(DatabaseReplacementRule::Pattern).text().not_null())
+                .col(ColumnDef::new(DatabaseReplacementRule::Replacement).text().not_null())
+                .col(ColumnDef::new(DatabaseReplacementRule::IsRegex).boolean().not_null().default(false))
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Table::drop()
+                .table(DatabaseReplacementRule::Table)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+impl Operation<Postgres> for CreateIndexOperation {
+    fn up<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Index::create()
+                .if_not_exists()
+                .name("replacement_rules_guild_id_position_idx")
+                .table(DatabaseReplacementRule::Table)
+                .col(DatabaseReplacementRule::GuildId)
+                .col(DatabaseReplacementRule::Position)
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+
+    fn down<'a, 'b, 'async_trait>(&'a self, connection: &'b mut PgConnection) -> BoxFuture<'async_trait, Result<(), sqlx_migrator::error::Error>>
+    where
+        Self: 'async_trait,
+        'a: 'async_trait,
+        'b: 'async_trait,
+    {
+        Box::pin(async {
+            let sql = Index::drop()
+                .name("replacement_rules_guild_id_position_idx")
+                .build(PostgresQueryBuilder);
+
+            sqlx::query(&sql).execute(&mut *connection).await?;
+
+            Ok(())
+        })
+    }
+}
+
+sqlx_migrator::migration!(
+    sqlx::Postgres,
+    V9Migration,
+    "seitai",
+    "create replacement_rules",
+    vec_box![],
+    vec_box![
+        CreateTableOperation,
+        CreateIndexOperation,
+    ]
+);
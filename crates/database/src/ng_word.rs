@@ -0,0 +1,91 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseNgWord {
+    #[iden = "ng_words"]
+    Table,
+    Id,
+    GuildId,
+    Pattern,
+    IsRegex,
+    /// `beep` to mask matches or `drop` not to read the whole message
+    Action,
+}
+
+/// Word that is not read aloud in a guild, which is a regex or a literal
+#[derive(Debug, Clone, FromRow)]
+pub struct NgWord {
+    pub id: Uuid,
+    pub guild_id: i64,
+    pub pattern: String,
+    pub is_regex: bool,
+    pub action: String,
+}
+
+const COLUMNS: [DatabaseNgWord; 5] = [
+    DatabaseNgWord::Id,
+    DatabaseNgWord::GuildId,
+    DatabaseNgWord::Pattern,
+    DatabaseNgWord::IsRegex,
+    DatabaseNgWord::Action,
+];
+
+pub async fn create(
+    database: &PgPool,
+    guild_id: u64,
+    pattern: impl Into<String>,
+    is_regex: bool,
+    action: impl Into<String>,
+) -> Result<NgWord> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseNgWord::Table)
+        .columns([
+            DatabaseNgWord::GuildId,
+            DatabaseNgWord::Pattern,
+            DatabaseNgWord::IsRegex,
+            DatabaseNgWord::Action,
+        ])
+        .values_panic([guild_id.into(), pattern.into().into(), is_regex.into(), action.into().into()])
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, NgWord, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+/// Fetches words of `guild_id` in alphabetical order of patterns.
+pub async fn fetch_by_guild_id(database: &PgPool, guild_id: u64) -> Result<Vec<NgWord>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseNgWord::Table)
+        .and_where(Expr::col(DatabaseNgWord::GuildId).eq(guild_id))
+        .order_by(DatabaseNgWord::Pattern, Order::Asc)
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, NgWord, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, guild_id: u64, id: Uuid) -> Result<Option<NgWord>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseNgWord::Table)
+        .and_where(Expr::col(DatabaseNgWord::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseNgWord::Id).eq(id))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, NgWord, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
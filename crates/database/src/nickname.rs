@@ -0,0 +1,75 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseNickname {
+    #[iden = "nicknames"]
+    Table,
+    UserId,
+    GuildId,
+    Reading,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct Nickname {
+    pub user_id: i64,
+    pub guild_id: i64,
+    pub reading: String,
+}
+
+const COLUMNS: [DatabaseNickname; 3] = [DatabaseNickname::UserId, DatabaseNickname::GuildId, DatabaseNickname::Reading];
+
+pub async fn create(database: &PgPool, user_id: u64, guild_id: u64, reading: impl Into<String>) -> Result<Nickname> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseNickname::Table)
+        .columns(COLUMNS)
+        .values_panic([user_id.into(), guild_id.into(), reading.into().into()])
+        .on_conflict(
+            OnConflict::columns([DatabaseNickname::UserId, DatabaseNickname::GuildId])
+                .update_column(DatabaseNickname::Reading)
+                .to_owned(),
+        )
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Nickname, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, user_id: u64, guild_id: u64) -> Result<Option<Nickname>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseNickname::Table)
+        .and_where(Expr::col(DatabaseNickname::UserId).eq(user_id))
+        .and_where(Expr::col(DatabaseNickname::GuildId).eq(guild_id))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Nickname, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_by_user_ids(database: &PgPool, guild_id: u64, user_ids: &[u64]) -> Result<Vec<Nickname>> {
+    if user_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseNickname::Table)
+        .and_where(Expr::col(DatabaseNickname::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseNickname::UserId).is_in(user_ids.iter().copied()))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Nickname, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
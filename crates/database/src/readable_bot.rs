@@ -0,0 +1,71 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseReadableBot {
+    #[iden = "readable_bots"]
+    Table,
+    GuildId,
+    /// ID of a bot user or a webhook
+    BotId,
+}
+
+/// Bot or webhook whose messages are read in a guild though bots are ignored by default
+#[derive(Debug, Clone, FromRow)]
+pub struct ReadableBot {
+    pub guild_id: i64,
+    pub bot_id: i64,
+}
+
+const COLUMNS: [DatabaseReadableBot; 2] = [DatabaseReadableBot::GuildId, DatabaseReadableBot::BotId];
+
+/// Makes `bot_id` readable, where `None` is returned if it has already been readable.
+pub async fn create(database: &PgPool, guild_id: u64, bot_id: u64) -> Result<Option<ReadableBot>> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseReadableBot::Table)
+        .columns(COLUMNS)
+        .values_panic([guild_id.into(), bot_id.into()])
+        .on_conflict(
+            OnConflict::columns(COLUMNS)
+                .do_nothing()
+                .to_owned(),
+        )
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, ReadableBot, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, guild_id: u64, bot_id: u64) -> Result<Option<ReadableBot>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseReadableBot::Table)
+        .and_where(Expr::col(DatabaseReadableBot::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseReadableBot::BotId).eq(bot_id))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, ReadableBot, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_by_guild_id(database: &PgPool, guild_id: u64) -> Result<Vec<ReadableBot>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseReadableBot::Table)
+        .and_where(Expr::col(DatabaseReadableBot::GuildId).eq(guild_id))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, ReadableBot, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
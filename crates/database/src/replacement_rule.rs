@@ -0,0 +1,143 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Func, Iden, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseReplacementRule {
+    #[iden = "replacement_rules"]
+    Table,
+    Id,
+    GuildId,
+    Position,
+    Pattern,
+    Replacement,
+    IsRegex,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ReplacementRule {
+    pub id: Uuid,
+    pub guild_id: i64,
+    pub position: i32,
+    pub pattern: String,
+    pub replacement: String,
+    pub is_regex: bool,
+}
+
+const COLUMNS: [DatabaseReplacementRule; 6] = [
+    DatabaseReplacementRule::Id,
+    DatabaseReplacementRule::GuildId,
+    DatabaseReplacementRule::Position,
+    DatabaseReplacementRule::Pattern,
+    DatabaseReplacementRule::Replacement,
+    DatabaseReplacementRule::IsRegex,
+];
+
+/// Appends a rule to the end of rules of `guild_id`.
+pub async fn create(
+    database: &PgPool,
+    guild_id: u64,
+    pattern: impl Into<String>,
+    replacement: impl Into<String>,
+    is_regex: bool,
+) -> Result<ReplacementRule> {
+    let mut tx = database.begin().await?;
+
+    let (sql, values) = Query::select()
+        .expr(Func::coalesce([
+            Func::max(Expr::col(DatabaseReplacementRule::Position)).into(),
+            Expr::val(-1).into(),
+        ]))
+        .from(DatabaseReplacementRule::Table)
+        .and_where(Expr::col(DatabaseReplacementRule::GuildId).eq(guild_id))
+        .build_sqlx(PostgresQueryBuilder);
+
+    let (last_position,): (i32,) = sqlx::query_as_with(&sql, values)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(Error::msg)?;
+
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseReplacementRule::Table)
+        .columns([
+            DatabaseReplacementRule::GuildId,
+            DatabaseReplacementRule::Position,
+            DatabaseReplacementRule::Pattern,
+            DatabaseReplacementRule::Replacement,
+            DatabaseReplacementRule::IsRegex,
+        ])
+        .values_panic([
+            guild_id.into(),
+            (last_position + 1).into(),
+            pattern.into().into(),
+            replacement.into().into(),
+            is_regex.into(),
+        ])
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    let rule = sqlx::query_as_with::<_, ReplacementRule, _>(&sql, values)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(Error::msg)?;
+
+    tx.commit().await?;
+
+    Ok(rule)
+}
+
+/// Fetches rules of `guild_id` in order of application.
+pub async fn fetch_by_guild_id(database: &PgPool, guild_id: u64) -> Result<Vec<ReplacementRule>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseReplacementRule::Table)
+        .and_where(Expr::col(DatabaseReplacementRule::GuildId).eq(guild_id))
+        .order_by(DatabaseReplacementRule::Position, Order::Asc)
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, ReplacementRule, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, guild_id: u64, id: Uuid) -> Result<Option<ReplacementRule>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseReplacementRule::Table)
+        .and_where(Expr::col(DatabaseReplacementRule::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseReplacementRule::Id).eq(id))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, ReplacementRule, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+/// Renumbers positions of rules of `guild_id` in order of `ids`.
+pub async fn reorder(database: &PgPool, guild_id: u64, ids: &[Uuid]) -> Result<Vec<ReplacementRule>> {
+    let mut tx = database.begin().await?;
+
+    for (position, id) in ids.iter().enumerate() {
+        let (sql, values) = Query::update()
+            .table(DatabaseReplacementRule::Table)
+            .value(DatabaseReplacementRule::Position, position as i32)
+            .and_where(Expr::col(DatabaseReplacementRule::GuildId).eq(guild_id))
+            .and_where(Expr::col(DatabaseReplacementRule::Id).eq(*id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&mut *tx)
+            .await
+            .map_err(Error::msg)?;
+    }
+
+    tx.commit().await?;
+
+    fetch_by_guild_id(database, guild_id).await
+}
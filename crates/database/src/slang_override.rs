@@ -0,0 +1,82 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseSlangOverride {
+    #[iden = "slang_overrides"]
+    Table,
+    GuildId,
+    /// Name of built-in slang
+    Slang,
+    Reading,
+}
+
+/// Setting of built-in slang in a guild, where the slang is not expanded if `reading` is absent
+#[derive(Debug, Clone, FromRow)]
+pub struct SlangOverride {
+    pub guild_id: i64,
+    pub slang: String,
+    pub reading: Option<String>,
+}
+
+const COLUMNS: [DatabaseSlangOverride; 3] = [
+    DatabaseSlangOverride::GuildId,
+    DatabaseSlangOverride::Slang,
+    DatabaseSlangOverride::Reading,
+];
+
+pub async fn create(
+    database: &PgPool,
+    guild_id: u64,
+    slang: impl Into<String>,
+    reading: Option<String>,
+) -> Result<SlangOverride> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseSlangOverride::Table)
+        .columns(COLUMNS)
+        .values_panic([guild_id.into(), slang.into().into(), reading.into()])
+        .on_conflict(
+            OnConflict::columns([DatabaseSlangOverride::GuildId, DatabaseSlangOverride::Slang])
+                .update_column(DatabaseSlangOverride::Reading)
+                .to_owned(),
+        )
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, SlangOverride, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn delete(database: &PgPool, guild_id: u64, slang: &str) -> Result<Option<SlangOverride>> {
+    let (sql, values) = Query::delete()
+        .from_table(DatabaseSlangOverride::Table)
+        .and_where(Expr::col(DatabaseSlangOverride::GuildId).eq(guild_id))
+        .and_where(Expr::col(DatabaseSlangOverride::Slang).eq(slang))
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, SlangOverride, _>(&sql, values)
+        .fetch_optional(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_by_guild_id(database: &PgPool, guild_id: u64) -> Result<Vec<SlangOverride>> {
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseSlangOverride::Table)
+        .and_where(Expr::col(DatabaseSlangOverride::GuildId).eq(guild_id))
+        .order_by(DatabaseSlangOverride::Slang, Order::Asc)
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, SlangOverride, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
@@ -39,7 +39,7 @@ pub struct Soundsticker {
 
 impl FromRow<'_, PgRow> for Soundsticker {
     fn from_row(row: &'_ PgRow) -> std::result::Result<Self, sqlx::Error> {
-        let sticker_id: i64 = row.try_get("sound_id")?;
+        let sticker_id: i64 = row.try_get("sticker_id")?;
         let sound_id: i64 = row.try_get("sound_id")?;
 
         Ok(Self {
@@ -1,7 +1,8 @@
 use std::fmt::Debug;
 
 use anyhow::{Error, Result};
-use sea_query::{Iden, OnConflict, PostgresQueryBuilder, Query};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, PostgresQueryBuilder, Query};
 use sea_query_binder::SqlxBinder;
 use sqlx::{FromRow, PgPool};
 
@@ -42,3 +43,19 @@ where
         .await
         .map_err(Error::msg)
 }
+
+pub async fn fetch_by_id(database: &PgPool, id: u16) -> Result<Option<Speaker>> {
+    let (sql, values) = Query::select()
+        .columns([DatabaseSpeaker::Id, DatabaseSpeaker::Speed])
+        .from(DatabaseSpeaker::Table)
+        .and_where(Expr::col(DatabaseSpeaker::Id).eq(id))
+        .build_sqlx(PostgresQueryBuilder);
+
+    let speakers: Vec<Speaker> = sqlx::query_as_with::<_, Speaker, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)?;
+
+    Ok(speakers.into_iter().next())
+}
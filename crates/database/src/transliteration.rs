@@ -0,0 +1,62 @@
+use anyhow::{Error, Result};
+use futures::TryStreamExt;
+use sea_query::{Expr, Iden, OnConflict, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Iden, Clone, Copy)]
+pub(crate) enum DatabaseTransliteration {
+    #[iden = "transliterations"]
+    Table,
+    Word,
+    Katakana,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct Transliteration {
+    pub word: String,
+    pub katakana: String,
+}
+
+const COLUMNS: [DatabaseTransliteration; 2] = [DatabaseTransliteration::Word, DatabaseTransliteration::Katakana];
+
+pub async fn create(
+    database: &PgPool,
+    word: impl Into<String>,
+    katakana: impl Into<String>,
+) -> Result<Transliteration> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseTransliteration::Table)
+        .columns(COLUMNS)
+        .values_panic([word.into().into(), katakana.into().into()])
+        .on_conflict(
+            OnConflict::column(DatabaseTransliteration::Word)
+                .update_column(DatabaseTransliteration::Katakana)
+                .to_owned(),
+        )
+        .returning(Query::returning().columns(COLUMNS))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Transliteration, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn fetch_by_words(database: &PgPool, words: &[&str]) -> Result<Vec<Transliteration>> {
+    if words.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let (sql, values) = Query::select()
+        .columns(COLUMNS)
+        .from(DatabaseTransliteration::Table)
+        .and_where(Expr::col(DatabaseTransliteration::Word).is_in(words.iter().copied()))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, Transliteration, _>(&sql, values)
+        .fetch(&mut *database.acquire().await?)
+        .try_collect()
+        .await
+        .map_err(Error::msg)
+}
@@ -12,12 +12,14 @@ pub(crate) enum DatabaseUser {
     Table,
     Id,
     SpeakerId,
+    ReadRomaji,
 }
 
 #[derive(Debug, FromRow)]
 pub struct User {
     pub id: i64,
     pub speaker_id: i32,
+    pub read_romaji: bool,
 }
 
 #[derive(Debug, FromRow)]
@@ -29,7 +31,11 @@ pub struct UserSpeaker {
 
 impl Default for User {
     fn default() -> Self {
-        Self { id: 0, speaker_id: 1 }
+        Self {
+            id: 0,
+            speaker_id: 1,
+            read_romaji: false,
+        }
     }
 }
 
@@ -53,7 +59,26 @@ pub async fn create(database: &PgPool, user_id: u64, speaker_id: u16) -> Result<
                 .update_column(DatabaseUser::SpeakerId)
                 .to_owned(),
         )
-        .returning(Query::returning().columns([DatabaseUser::Id, DatabaseUser::SpeakerId]))
+        .returning(Query::returning().columns([DatabaseUser::Id, DatabaseUser::SpeakerId, DatabaseUser::ReadRomaji]))
+        .build_sqlx(PostgresQueryBuilder);
+
+    sqlx::query_as_with::<_, User, _>(&sql, values)
+        .fetch_one(&mut *database.acquire().await?)
+        .await
+        .map_err(Error::msg)
+}
+
+pub async fn update_read_romaji(database: &PgPool, user_id: u64, read_romaji: bool) -> Result<User> {
+    let (sql, values) = Query::insert()
+        .into_table(DatabaseUser::Table)
+        .columns([DatabaseUser::Id, DatabaseUser::SpeakerId, DatabaseUser::ReadRomaji])
+        .values_panic([user_id.into(), User::default().speaker_id.into(), read_romaji.into()])
+        .on_conflict(
+            OnConflict::column(DatabaseUser::Id)
+                .update_column(DatabaseUser::ReadRomaji)
+                .to_owned(),
+        )
+        .returning(Query::returning().columns([DatabaseUser::Id, DatabaseUser::SpeakerId, DatabaseUser::ReadRomaji]))
         .build_sqlx(PostgresQueryBuilder);
 
     sqlx::query_as_with::<_, User, _>(&sql, values)
@@ -64,7 +89,7 @@ pub async fn create(database: &PgPool, user_id: u64, speaker_id: u16) -> Result<
 
 pub async fn fetch_by_ids(database: &PgPool, ids: &[i64]) -> Result<Vec<User>> {
     let (sql, values) = Query::select()
-        .columns([DatabaseUser::Id, DatabaseUser::SpeakerId])
+        .columns([DatabaseUser::Id, DatabaseUser::SpeakerId, DatabaseUser::ReadRomaji])
         .from(DatabaseUser::Table)
         .and_where(Expr::col(DatabaseUser::Id).is_in(ids.iter().cloned()))
         .build_sqlx(PostgresQueryBuilder);
@@ -0,0 +1,120 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, PoisonError},
+};
+
+/// Cache of transliterations in process, where `None` is cached for words that kanatrans cannot convert
+#[derive(Debug)]
+pub struct MemoryCache {
+    entries: Mutex<Entries>,
+    capacity: usize,
+}
+
+/// Transliterations with the time they were last used, where `recency` orders words from least recently used
+#[derive(Debug, Default)]
+struct Entries {
+    katakana: HashMap<String, (Option<String>, u64)>,
+    recency: BTreeMap<u64, String>,
+    clock: u64,
+}
+
+impl Entries {
+    /// Marks `word` as used now and returns its transliteration if it is cached.
+    fn touch(&mut self, word: &str) -> Option<&mut (Option<String>, u64)> {
+        let clock = self.clock;
+        let entry = self.katakana.get_mut(word)?;
+        self.recency.remove(&entry.1);
+        self.recency.insert(clock, word.to_string());
+        entry.1 = clock;
+        self.clock += 1;
+
+        Some(entry)
+    }
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(Entries::default()),
+            capacity,
+        }
+    }
+
+    /// Returns `None` if `word` is not cached, or `Some(None)` if it is cached as unconvertible.
+    pub fn get(&self, word: &str) -> Option<Option<String>> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.touch(word).map(|(katakana, _)| katakana.clone())
+    }
+
+    /// Caches `katakana` of `word`, evicting the least recently used entry when the cache is full.
+    pub fn insert(&self, word: impl Into<String>, katakana: Option<String>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let word = word.into();
+        if let Some(entry) = entries.touch(&word) {
+            entry.0 = katakana;
+            return;
+        }
+
+        if entries.katakana.len() >= self.capacity
+            && let Some((_, evicted)) = entries.recency.pop_first()
+        {
+            entries.katakana.remove(&evicted);
+        }
+        let clock = entries.clock;
+        entries.katakana.insert(word.clone(), (katakana, clock));
+        entries.recency.insert(clock, word);
+        entries.clock += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryCache;
+
+    #[test]
+    fn get_cached() {
+        let cache = MemoryCache::new(2);
+        cache.insert("hello", Some("ハロー".to_string()));
+        cache.insert("xyzzy", None);
+
+        assert_eq!(cache.get("hello"), Some(Some("ハロー".to_string())));
+        assert_eq!(cache.get("xyzzy"), Some(None));
+        assert_eq!(cache.get("world"), None);
+    }
+
+    #[test]
+    fn evict_least_recently_used() {
+        let cache = MemoryCache::new(2);
+        cache.insert("a", Some("エー".to_string()));
+        cache.insert("b", Some("ビー".to_string()));
+        cache.get("a");
+        cache.insert("c", Some("シー".to_string()));
+
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn overwrite_without_eviction() {
+        let cache = MemoryCache::new(2);
+        cache.insert("a", None);
+        cache.insert("b", None);
+        cache.insert("a", Some("エー".to_string()));
+
+        assert_eq!(cache.get("a"), Some(Some("エー".to_string())));
+        assert!(cache.get("b").is_some());
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let cache = MemoryCache::new(0);
+        cache.insert("a", None);
+
+        assert!(cache.get("a").is_none());
+    }
+}
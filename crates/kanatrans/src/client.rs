@@ -0,0 +1,123 @@
+use http_body_util::{BodyExt, Empty};
+use hyper::{StatusCode, Uri, body::Bytes};
+use hyper_util::{
+    client::legacy::{Client as HttpClient, connect::HttpConnector},
+    rt::TokioExecutor,
+};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::{
+    error::KanatransError,
+    response::{Arpabet, Katakana},
+};
+
+/// Client of kanatrans, which reuses connections in a pool
+#[derive(Debug, Clone)]
+pub struct Client {
+    base: Url,
+    http: HttpClient<HttpConnector, Empty<Bytes>>,
+}
+
+impl Client {
+    pub fn new(host: &str, port: u16) -> Result<Self, KanatransError> {
+        let base = Url::parse(&format!("http://{host}:{port}")).map_err(KanatransError::InvalidUrl)?;
+        let http = HttpClient::builder(TokioExecutor::new()).build_http();
+
+        Ok(Self { base, http })
+    }
+
+    pub async fn arpabet(&self, word: &str) -> Result<Arpabet, KanatransError> {
+        let url = self.url(&["arpabet", word], &[]);
+        match self.get(&url).await? {
+            (StatusCode::OK, body) => deserialize(&body),
+            (StatusCode::UNPROCESSABLE_ENTITY, _) => Err(KanatransError::Unconvertible(word.to_string())),
+            (status, _) => Err(KanatransError::UnexpectedStatus {
+                status,
+                url: url.to_string(),
+            }),
+        }
+    }
+
+    pub async fn katakana(&self, word: Option<&str>, pronunciation: &[String]) -> Result<Katakana, KanatransError> {
+        let pronunciation = pronunciation.join(" ");
+        let mut parameters = vec![("pronunciation", pronunciation.as_str())];
+        if let Some(word) = word {
+            parameters.push(("word", word));
+        }
+
+        let url = self.url(&["katakana"], &parameters);
+        match self.get(&url).await? {
+            (StatusCode::OK, body) => deserialize(&body),
+            (status, _) => Err(KanatransError::UnexpectedStatus {
+                status,
+                url: url.to_string(),
+            }),
+        }
+    }
+
+    /// Converts `word` into ARPAbet and then into katakana.
+    pub async fn transliterate(&self, word: &str) -> Result<String, KanatransError> {
+        let arpabet = self.arpabet(word).await?;
+        let katakana = self.katakana(Some(&arpabet.word), &arpabet.pronunciation).await?;
+
+        Ok(katakana.pronunciation)
+    }
+
+    fn url(&self, segments: &[&str], parameters: &[(&str, &str)]) -> Url {
+        let mut url = self.base.clone();
+        if let Ok(mut path) = url.path_segments_mut() {
+            path.extend(segments);
+        }
+        if !parameters.is_empty() {
+            url.query_pairs_mut().extend_pairs(parameters);
+        }
+        url
+    }
+
+    async fn get(&self, url: &Url) -> Result<(StatusCode, Bytes), KanatransError> {
+        let uri = url.as_str().parse::<Uri>().map_err(KanatransError::InvalidUri)?;
+        let response = self.http.get(uri).await.map_err(KanatransError::RequestError)?;
+        let status = response.status();
+        let body = response
+            .into_body()
+            .collect()
+            .await
+            .map_err(KanatransError::BodyError)?
+            .to_bytes();
+
+        Ok((status, body))
+    }
+}
+
+fn deserialize<Response: DeserializeOwned>(body: &[u8]) -> Result<Response, KanatransError> {
+    serde_json::from_slice(body).map_err(KanatransError::DeserializeError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Client, deserialize};
+    use crate::{error::KanatransError, response::Katakana};
+
+    #[test]
+    fn reject_invalid_host() {
+        assert!(matches!(Client::new("invalid host", 8080), Err(KanatransError::InvalidUrl(_))));
+    }
+
+    #[test]
+    fn build_url() {
+        let client = Client::new("localhost", 8080).unwrap();
+
+        assert_eq!(client.url(&["arpabet", "rock n/roll"], &[]).as_str(), "http://localhost:8080/arpabet/rock%20n%2Froll");
+        assert_eq!(
+            client.url(&["katakana"], &[("pronunciation", "HH AH0"), ("word", "a&b")]).as_str(),
+            "http://localhost:8080/katakana?pronunciation=HH+AH0&word=a%26b",
+        );
+    }
+
+    #[test]
+    fn map_deserialize_error() {
+        assert!(matches!(deserialize::<Katakana>(b"not json"), Err(KanatransError::DeserializeError(_))));
+        assert!(deserialize::<Katakana>(r#"{"pronunciation":"ハロー"}"#.as_bytes()).is_ok());
+    }
+}
@@ -0,0 +1,26 @@
+use hyper::{StatusCode, http::uri::InvalidUri};
+use url::ParseError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum KanatransError {
+    #[error("invalid url: {0}")]
+    InvalidUrl(#[source] ParseError),
+
+    #[error("invalid uri: {0}")]
+    InvalidUri(#[source] InvalidUri),
+
+    #[error("failed to request: {0}")]
+    RequestError(#[source] hyper_util::client::legacy::Error),
+
+    #[error("failed to read response body: {0}")]
+    BodyError(#[source] hyper::Error),
+
+    #[error("failed to deserialize response: {0}")]
+    DeserializeError(#[source] serde_json::Error),
+
+    #[error("cannot convert {0} to ARPAbet")]
+    Unconvertible(String),
+
+    #[error("received unexpected {status} from GET {url}")]
+    UnexpectedStatus { status: StatusCode, url: String },
+}
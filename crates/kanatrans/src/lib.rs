@@ -0,0 +1,8 @@
+pub mod cache;
+pub mod client;
+pub mod error;
+pub mod response;
+
+pub use cache::MemoryCache;
+pub use client::Client;
+pub use error::KanatransError;
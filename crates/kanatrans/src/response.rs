@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Arpabet {
+    pub word: String,
+    pub pronunciation: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Katakana {
+    pub pronunciation: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arpabet, Katakana};
+
+    #[test]
+    fn deserialize_arpabet() {
+        let arpabet: Arpabet = serde_json::from_str(r#"{"word":"hello","pronunciation":["HH","AH0","L","OW1"]}"#).unwrap();
+
+        assert_eq!(arpabet.word, "hello");
+        assert_eq!(arpabet.pronunciation, ["HH", "AH0", "L", "OW1"]);
+    }
+
+    #[test]
+    fn deserialize_katakana() {
+        let katakana: Katakana = serde_json::from_str(r#"{"pronunciation":"ハロー"}"#).unwrap();
+
+        assert_eq!(katakana.pronunciation, "ハロー");
+    }
+
+    #[test]
+    fn reject_missing_fields() {
+        assert!(serde_json::from_str::<Arpabet>(r#"{"word":"hello"}"#).is_err());
+        assert!(serde_json::from_str::<Katakana>("{}").is_err());
+    }
+}
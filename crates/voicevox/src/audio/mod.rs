@@ -37,6 +37,8 @@ pub struct AccentPhrases {
 #[derive(Debug, Clone)]
 pub struct AudioGenerator {
     pub default_speed: f32,
+    pub output_sampling_rate: u32,
+    pub output_stereo: bool,
     pub(crate) base: Url,
 }
 
@@ -102,6 +104,8 @@ impl AudioGenerator {
             .map(|accent_phrases| accent_phrases.moras.len())
             .sum::<usize>();
         audio_query.speed_scale = speed + (mora_length / 50) as f32 * 0.1;
+        audio_query.output_sampling_rate = self.output_sampling_rate;
+        audio_query.output_stereo = self.output_stereo;
 
         let json = serde_json::to_string(&audio_query)?;
         match self
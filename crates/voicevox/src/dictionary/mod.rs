@@ -1,4 +1,5 @@
 pub mod response;
+pub mod validation;
 
 use anyhow::{bail, Result};
 use http_body_util::Empty;
@@ -6,7 +7,10 @@ use hyper::{body::Bytes, StatusCode};
 use url::Url;
 use uuid::Uuid;
 
-use self::response::{DeleteUserDictWordResult, GetUserDictResult, PostUserDictWordResult, PutUserDictWordResult};
+use self::{
+    response::{DeleteUserDictWordResult, GetUserDictResult, PostUserDictWordResult, PutUserDictWordResult},
+    validation::validate_word,
+};
 use crate::request::Request;
 
 #[derive(Debug, Clone)]
@@ -30,6 +34,7 @@ impl Dictionary {
     }
 
     pub async fn register_word(&self, parameters: &[(&str, &str)]) -> Result<PostUserDictWordResult> {
+        validate_word(parameters)?;
         let (status, bytes) = self.post("user_dict_word", parameters, Empty::<Bytes>::new()).await?;
         match status {
             StatusCode::OK => Ok(PostUserDictWordResult::Ok(Uuid::parse_str(&String::from_utf8(
@@ -43,6 +48,7 @@ impl Dictionary {
     }
 
     pub async fn update_word(&self, uuid: &Uuid, parameters: &[(&str, &str)]) -> Result<PutUserDictWordResult> {
+        validate_word(parameters)?;
         let (status, bytes) = self
             .put(&format!("user_dict_word/{uuid}"), parameters, Empty::<Bytes>::new())
             .await?;
@@ -24,6 +24,20 @@ pub struct Item {
     pub surface: String,
 }
 
+impl Item {
+    /// Returns `word_type` of the engine that the part of speech of this word corresponds to.
+    pub fn word_type(&self) -> Option<&'static str> {
+        match (self.part_of_speech.as_str(), self.part_of_speech_detail_1.as_str()) {
+            ("名詞", "固有名詞") => Some("PROPER_NOUN"),
+            ("名詞", "一般") => Some("COMMON_NOUN"),
+            ("名詞", "接尾") => Some("SUFFIX"),
+            ("動詞", _) => Some("VERB"),
+            ("形容詞", _) => Some("ADJECTIVE"),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum GetUserDictResult {
     Ok(UserDict),
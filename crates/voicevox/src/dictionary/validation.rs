@@ -0,0 +1,128 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, ensure, Context as _, Result};
+
+pub const PRIORITY_RANGE: RangeInclusive<u32> = 0..=10;
+/// Values of `word_type` that the engine accepts
+pub const WORD_TYPES: [&str; 5] = ["PROPER_NOUN", "COMMON_NOUN", "VERB", "ADJECTIVE", "SUFFIX"];
+
+/// Small katakana that are pronounced together with the preceding one as a single mora
+const SMALL_KANA: [char; 9] = ['ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ヮ'];
+
+/// Validates `parameters` of a word before they are sent to the engine, which otherwise rejects them with a less
+/// descriptive error.
+pub fn validate_word(parameters: &[(&str, &str)]) -> Result<()> {
+    let get = |name: &str| parameters.iter().find(|(key, _)| *key == name).map(|(_, value)| *value);
+
+    let pronunciation = get("pronunciation").context("ヨミを入力してください")?;
+    ensure!(!pronunciation.is_empty(), "ヨミを入力してください");
+    if let Some(invalid) = pronunciation.chars().find(|c| !is_katakana(*c)) {
+        bail!("ヨミはカタカナで入力してください: {pronunciation} に {invalid} が含まれています");
+    }
+
+    if let Some(accent_type) = get("accent_type") {
+        let accent_type = accent_type
+            .parse::<u32>()
+            .with_context(|| format!("音が下がる位置は 0 以上の整数で入力してください: {accent_type}"))?;
+        let mora_count = count_mora(pronunciation);
+        ensure!(
+            accent_type <= mora_count,
+            "音が下がる位置は {pronunciation} の音の数（{mora_count}）以下で入力してください: {accent_type}"
+        );
+    }
+
+    if let Some(word_type) = get("word_type") {
+        ensure!(
+            WORD_TYPES.contains(&word_type),
+            "単語の種類は {} のいずれかで入力してください: {word_type}",
+            WORD_TYPES.join("・")
+        );
+    }
+
+    if let Some(priority) = get("priority") {
+        let priority = priority
+            .parse::<u32>()
+            .with_context(|| format!("優先度は 0 以上の整数で入力してください: {priority}"))?;
+        ensure!(
+            PRIORITY_RANGE.contains(&priority),
+            "優先度は {} 〜 {} で入力してください: {priority}",
+            PRIORITY_RANGE.start(),
+            PRIORITY_RANGE.end()
+        );
+    }
+
+    Ok(())
+}
+
+/// Counts morae of katakana `pronunciation`, where small kana except "ッ" make a mora with the preceding one.
+pub fn count_mora(pronunciation: &str) -> u32 {
+    pronunciation
+        .chars()
+        .filter(|c| !SMALL_KANA.contains(c))
+        .count()
+        .try_into()
+        .unwrap_or(u32::MAX)
+}
+
+fn is_katakana(c: char) -> bool {
+    matches!(c, 'ァ'..='ヴ' | 'ー')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_mora, validate_word};
+
+    #[test]
+    fn count_mora_of_pronunciation() {
+        let cases = [
+            ("カタカナ", 4),
+            ("キャット", 3),
+            ("ティー", 2),
+            ("ラーメン", 4),
+            ("クヮ", 1),
+            ("ッ", 1),
+            ("", 0),
+        ];
+        for (pronunciation, expected) in cases {
+            assert_eq!(count_mora(pronunciation), expected, "{pronunciation}");
+        }
+    }
+
+    #[test]
+    fn accept_valid_word() {
+        let cases: [&[(&str, &str)]; 7] = [
+            &[("pronunciation", "テスト")],
+            &[("pronunciation", "テスト"), ("accent_type", "0")],
+            &[("pronunciation", "テスト"), ("accent_type", "3")],
+            &[("pronunciation", "キャット"), ("accent_type", "3"), ("priority", "10")],
+            &[("pronunciation", "ヴァイオリン"), ("priority", "0")],
+            &[("pronunciation", "テスト"), ("word_type", "COMMON_NOUN")],
+            &[("pronunciation", "ハシル"), ("accent_type", "2"), ("word_type", "VERB")],
+        ];
+        for parameters in cases {
+            assert!(validate_word(parameters).is_ok(), "{parameters:?}");
+        }
+    }
+
+    #[test]
+    fn reject_invalid_word() {
+        let cases: [&[(&str, &str)]; 13] = [
+            &[],
+            &[("pronunciation", "")],
+            &[("pronunciation", "てすと")],
+            &[("pronunciation", "テスト1")],
+            &[("pronunciation", "ヵ")],
+            &[("pronunciation", "ヶ")],
+            &[("pronunciation", "キャット"), ("accent_type", "4")],
+            &[("pronunciation", "テスト"), ("accent_type", "-1")],
+            &[("pronunciation", "テスト"), ("priority", "11")],
+            &[("pronunciation", "テスト"), ("priority", "high")],
+            &[("pronunciation", "テスト"), ("word_type", "NOUN")],
+            &[("pronunciation", "テスト"), ("word_type", "proper_noun")],
+            &[("pronunciation", "テスト"), ("word_type", "")],
+        ];
+        for parameters in cases {
+            assert!(validate_word(parameters).is_err(), "{parameters:?}");
+        }
+    }
+}
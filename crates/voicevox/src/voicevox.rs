@@ -3,6 +3,11 @@ use url::Url;
 
 use crate::{audio::AudioGenerator, dictionary::Dictionary, speaker::Speaker};
 
+/// Sampling rate of Discord voice, which songbird outputs without resampling
+pub const DISCORD_SAMPLING_RATE: u32 = 48_000;
+/// Sampling rates that VOICEVOX ENGINE offers for output audio
+pub const SAMPLING_RATES: [u32; 5] = [24_000, 44_100, 48_000, 88_200, 96_000];
+
 pub struct Voicevox {
     pub audio_generator: AudioGenerator,
     pub dictionary: Dictionary,
@@ -17,6 +22,8 @@ impl Voicevox {
             audio_generator: AudioGenerator {
                 base: base.clone(),
                 default_speed: 1.2,
+                output_sampling_rate: DISCORD_SAMPLING_RATE,
+                output_stereo: true,
             },
             dictionary: Dictionary { base: base.clone() },
             speaker: Speaker { base },
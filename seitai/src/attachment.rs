@@ -0,0 +1,195 @@
+use std::{fmt, path::Path, str::FromStr};
+
+use serenity::all::Attachment;
+use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
+
+/// Maximum number of attachments in a message
+const MAX_ATTACHMENTS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumString, AsRefStr)]
+pub(crate) enum AttachmentKind {
+    #[strum(serialize = "画像")]
+    Image,
+    #[strum(serialize = "動画")]
+    Video,
+    #[strum(serialize = "音声")]
+    Audio,
+    #[strum(serialize = "PDFファイル")]
+    Pdf,
+    #[strum(serialize = "テキストファイル")]
+    Text,
+    #[strum(serialize = "圧縮ファイル")]
+    Archive,
+    #[strum(serialize = "添付ファイル")]
+    Other,
+}
+
+/// Description of attachments of the same kind such as "画像2枚", which is cached as a predefined utterance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct AttachmentUtterance {
+    pub(crate) kind: AttachmentKind,
+    pub(crate) count: usize,
+}
+
+impl AttachmentKind {
+    pub(crate) fn of(filename: &str, content_type: Option<&str>) -> Self {
+        let content_type = content_type.map(|content_type| {
+            content_type
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase()
+        });
+        match content_type.as_deref() {
+            Some("application/pdf") => return Self::Pdf,
+            Some(content_type) if content_type.starts_with("image/") => return Self::Image,
+            Some(content_type) if content_type.starts_with("video/") => return Self::Video,
+            Some(content_type) if content_type.starts_with("audio/") => return Self::Audio,
+            Some(content_type) if content_type.starts_with("text/") => return Self::Text,
+            _ => {},
+        }
+
+        let extension = Path::new(filename)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("png" | "jpg" | "jpeg" | "gif" | "webp" | "avif" | "heic" | "bmp" | "svg") => Self::Image,
+            Some("mp4" | "mov" | "webm" | "mkv" | "avi") => Self::Video,
+            Some("mp3" | "wav" | "ogg" | "opus" | "flac" | "m4a" | "aac") => Self::Audio,
+            Some("pdf") => Self::Pdf,
+            Some("txt" | "md" | "csv" | "log" | "json") => Self::Text,
+            Some("zip" | "7z" | "rar" | "tar" | "gz" | "xz") => Self::Archive,
+            _ => Self::Other,
+        }
+    }
+
+    fn counter(&self) -> &'static str {
+        match self {
+            Self::Image => "枚",
+            Self::Video => "本",
+            _ => "個",
+        }
+    }
+}
+
+impl fmt::Display for AttachmentUtterance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.count {
+            1 => write!(f, "{}", self.kind.as_ref()),
+            count => write!(f, "{}{count}{}", self.kind.as_ref(), self.kind.counter()),
+        }
+    }
+}
+
+impl FromStr for AttachmentUtterance {
+    type Err = strum::ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        AttachmentKind::iter()
+            .flat_map(|kind| (1..=MAX_ATTACHMENTS).map(move |count| Self { kind, count }))
+            .find(|utterance| utterance.to_string() == text)
+            .ok_or(strum::ParseError::VariantNotFound)
+    }
+}
+
+/// Describes kinds and counts of `attachments` in order of appearance, followed by each filename without extension if
+/// `read_filename` is true.
+pub(crate) fn describe(attachments: &[Attachment], read_filename: bool) -> Vec<String> {
+    let mut utterances: Vec<(AttachmentUtterance, Vec<&str>)> = Vec::new();
+    for attachment in attachments {
+        let kind = AttachmentKind::of(&attachment.filename, attachment.content_type.as_deref());
+        let stem = Path::new(&attachment.filename)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or(&attachment.filename);
+
+        match utterances.iter_mut().find(|(utterance, _)| utterance.kind == kind) {
+            Some((utterance, filenames)) => {
+                utterance.count += 1;
+                filenames.push(stem);
+            },
+            None => utterances.push((AttachmentUtterance { kind, count: 1 }, vec![stem])),
+        }
+    }
+
+    utterances
+        .into_iter()
+        .flat_map(|(utterance, filenames)| {
+            let filenames = if read_filename { filenames } else { Vec::new() };
+            [utterance.to_string()]
+                .into_iter()
+                .chain(filenames.into_iter().map(str::to_string))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serenity::all::Attachment;
+
+    use super::{describe, AttachmentKind, AttachmentUtterance};
+
+    fn attachment(filename: &str, content_type: Option<&str>) -> Attachment {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "filename": filename,
+            "content_type": content_type,
+            "size": 0,
+            "url": "",
+            "proxy_url": "",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn kind_from_content_type() {
+        assert_eq!(AttachmentKind::of("foo", Some("image/png")), AttachmentKind::Image);
+        assert_eq!(AttachmentKind::of("foo", Some("video/mp4")), AttachmentKind::Video);
+        assert_eq!(AttachmentKind::of("foo", Some("audio/ogg")), AttachmentKind::Audio);
+        assert_eq!(AttachmentKind::of("foo", Some("application/pdf")), AttachmentKind::Pdf);
+        assert_eq!(AttachmentKind::of("foo", Some("text/plain; charset=utf-8")), AttachmentKind::Text);
+    }
+
+    #[test]
+    fn kind_from_extension() {
+        assert_eq!(AttachmentKind::of("foo.JPG", None), AttachmentKind::Image);
+        assert_eq!(AttachmentKind::of("foo.pdf", Some("application/octet-stream")), AttachmentKind::Pdf);
+        assert_eq!(AttachmentKind::of("foo.tar.gz", None), AttachmentKind::Archive);
+        assert_eq!(AttachmentKind::of("foo", None), AttachmentKind::Other);
+        assert_eq!(AttachmentKind::of("foo.exe", None), AttachmentKind::Other);
+    }
+
+    #[test]
+    fn utterance_round_trip() {
+        for (text, kind, count) in [
+            ("画像", AttachmentKind::Image, 1),
+            ("画像2枚", AttachmentKind::Image, 2),
+            ("動画10本", AttachmentKind::Video, 10),
+            ("PDFファイル3個", AttachmentKind::Pdf, 3),
+        ] {
+            let utterance = AttachmentUtterance { kind, count };
+            assert_eq!(utterance.to_string(), text);
+            assert_eq!(AttachmentUtterance::from_str(text), Ok(utterance));
+        }
+
+        assert!(AttachmentUtterance::from_str("画像11枚").is_err());
+        assert!(AttachmentUtterance::from_str("画像1枚").is_err());
+    }
+
+    #[test]
+    fn describe_groups_by_kind() {
+        let attachments = [
+            attachment("a.png", Some("image/png")),
+            attachment("b.pdf", Some("application/pdf")),
+            attachment("c.jpg", Some("image/jpeg")),
+        ];
+
+        assert_eq!(describe(&attachments, false), ["画像2枚", "PDFファイル"]);
+        assert_eq!(describe(&attachments, true), ["画像2枚", "a", "c", "PDFファイル", "b"]);
+        assert!(describe(&[], true).is_empty());
+    }
+}
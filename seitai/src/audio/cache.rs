@@ -2,18 +2,40 @@ use std::{marker::PhantomData, str::FromStr};
 
 use strum::{AsRefStr, EnumString};
 
+use crate::attachment::AttachmentUtterance;
+
 #[derive(Debug, Clone, EnumString, AsRefStr)]
 pub(crate) enum PredefinedUtterance {
     #[strum(serialize = "コード省略")]
     Code,
+    #[strum(serialize = "ネタバレ")]
+    Spoiler,
     #[strum(serialize = "URL")]
     Url,
+    #[strum(serialize = "メッセージリンク")]
+    MessageLink,
+    #[strum(serialize = "招待リンク")]
+    InviteLink,
     #[strum(serialize = "接続しました")]
     Connected,
-    #[strum(serialize = "添付ファイル")]
-    Attachment,
     #[strum(serialize = "を登録しました")]
     Registered,
+    #[strum(serialize = "ピー")]
+    Beep,
+}
+
+/// Utterances that are spoken repeatedly and worth caching, which are predefined ones or descriptions of attachments
+#[derive(Debug, Clone)]
+pub(crate) struct CachedUtterance;
+
+impl FromStr for CachedUtterance {
+    type Err = strum::ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        PredefinedUtterance::from_str(text)
+            .map(|_| Self)
+            .or_else(|_| AttachmentUtterance::from_str(text).map(|_| Self))
+    }
 }
 
 pub(crate) struct ConstCacheable<Utterance> {
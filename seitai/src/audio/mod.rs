@@ -12,6 +12,7 @@ use self::{cache::Cacheable, generator::AudioGenerator, processor::AudioProcesso
 
 pub mod cache;
 pub mod generator;
+pub mod pcm;
 pub mod processor;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -19,6 +20,7 @@ pub(crate) struct Audio {
     pub(crate) text: String,
     pub(crate) speaker: String,
     pub(crate) speed: NotNan<f32>,
+    pub(crate) volume: NotNan<f32>,
 }
 
 pub(crate) struct VoicevoxAudioRepository<AudioCacheable, Compressed, Generator, Input, Processor, Raw> {
@@ -74,6 +76,7 @@ where
             .audio_generator
             .generate(&audio.speaker, &audio.text, *audio.speed)
             .await?;
+        let raw = self.audio_processor.normalize(raw, *audio.volume)?;
 
         if self.cacheable.should_cache(&audio.text) {
             let compressed = self.audio_processor.compress(raw).await?;
@@ -103,6 +106,7 @@ mod tests {
             text: "foo".to_string(),
             speaker: "1".to_string(),
             speed: NotNan::new(1.0).unwrap(),
+            volume: NotNan::new(1.0).unwrap(),
         };
 
         let mut mock_cacheable = MockCacheable::new();
@@ -119,7 +123,12 @@ mod tests {
             .withf(|x, y, z| (x, y, z) == ("1", "foo", &1.0))
             .returning(|_, _, _| Box::pin(ok(vec![0x00, 0x01, 0x02, 0x03])));
 
-        let mock_audio_processor = MockAudioProcessor::new();
+        let mut mock_audio_processor = MockAudioProcessor::new();
+        mock_audio_processor
+            .expect_normalize()
+            .times(1)
+            .withf(|x, y| (x.as_slice(), y) == (&[0x00, 0x01, 0x02, 0x03], &1.0))
+            .returning(|x, _| Ok(x));
 
         let audio_repository = VoicevoxAudioRepository::new(mock_audio_generator, mock_audio_processor, mock_cacheable);
 
@@ -133,6 +142,7 @@ mod tests {
             text: "bar".to_string(),
             speaker: "1".to_string(),
             speed: NotNan::new(1.0).unwrap(),
+            volume: NotNan::new(1.0).unwrap(),
         };
 
         let mut mock_cacheable = MockCacheable::new();
@@ -150,6 +160,12 @@ mod tests {
             .returning(|_, _, _| Box::pin(ok(vec![0x00, 0x01, 0x02, 0x03])));
 
         let mut mock_audio_processor = MockAudioProcessor::new();
+        mock_audio_processor
+            .expect_normalize()
+            .times(1)
+            .withf(|x, y| (x.as_slice(), y) == (&[0x00, 0x01, 0x02, 0x03], &1.0))
+            .returning(|x, _| Ok(x));
+
         mock_audio_processor
             .expect_compress()
             .times(1)
@@ -170,4 +186,40 @@ mod tests {
         let actual = audio_repository.get(audio).await.unwrap();
         assert_eq!(actual, vec![0x00, 0x01, 0x02, 0x03]);
     }
+
+    #[tokio::test]
+    async fn get_normalized_audio() {
+        let audio = Audio {
+            text: "baz".to_string(),
+            speaker: "1".to_string(),
+            speed: NotNan::new(1.0).unwrap(),
+            volume: NotNan::new(0.5).unwrap(),
+        };
+
+        let mut mock_cacheable = MockCacheable::new();
+        mock_cacheable
+            .expect_should_cache()
+            .times(1)
+            .withf(|x| x == "baz")
+            .returning(|_| false);
+
+        let mut mock_audio_generator = MockAudioGenerator::new();
+        mock_audio_generator
+            .expect_generate()
+            .times(1)
+            .withf(|x, y, z| (x, y, z) == ("1", "baz", &1.0))
+            .returning(|_, _, _| Box::pin(ok(vec![0x00, 0x02, 0x04, 0x06])));
+
+        let mut mock_audio_processor = MockAudioProcessor::new();
+        mock_audio_processor
+            .expect_normalize()
+            .times(1)
+            .withf(|x, y| (x.as_slice(), y) == (&[0x00, 0x02, 0x04, 0x06], &0.5))
+            .returning(|x, _| Ok(x.into_iter().map(|v| v / 2).collect()));
+
+        let audio_repository = VoicevoxAudioRepository::new(mock_audio_generator, mock_audio_processor, mock_cacheable);
+
+        let actual = audio_repository.get(audio).await.unwrap();
+        assert_eq!(actual, vec![0x00, 0x01, 0x02, 0x03]);
+    }
 }
@@ -0,0 +1,215 @@
+use std::ops::Range;
+
+use anyhow::{bail, ensure, Context as _, Result};
+
+/// Loudness every utterance is normalized to, as RMS in dBFS.
+pub(crate) const TARGET_LOUDNESS: f32 = -20.0;
+/// Upper bound of the gain to avoid amplifying almost silent audio into noise (+12 dB).
+const MAX_GAIN: f32 = 4.0;
+/// Peak level that normalized samples must not exceed.
+const PEAK_CEILING: f32 = 0.98;
+
+const PCM_FORMAT: u16 = 1;
+const BITS_PER_SAMPLE: u16 = 16;
+
+/// Normalizes 16-bit PCM WAV to `target_loudness` dBFS and then scales it by `volume`.
+pub(crate) fn normalize(wav: &[u8], target_loudness: f32, volume: f32) -> Result<Vec<u8>> {
+    let range = data_range(wav)?;
+    let mut samples = wav[range.clone()]
+        .chunks_exact(2)
+        .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+        .collect::<Vec<_>>();
+
+    let gain = gain(rms(&samples), peak(&samples), target_loudness, volume);
+    apply_gain(&mut samples, gain);
+
+    let mut normalized = wav.to_vec();
+    for (bytes, sample) in normalized[range].chunks_exact_mut(2).zip(samples) {
+        bytes.copy_from_slice(&sample.to_le_bytes());
+    }
+
+    Ok(normalized)
+}
+
+/// Returns the byte range of samples in `data` chunk after validating that `wav` is 16-bit PCM.
+fn data_range(wav: &[u8]) -> Result<Range<usize>> {
+    ensure!(
+        wav.len() >= 12 && &wav[0..4] == b"RIFF" && &wav[8..12] == b"WAVE",
+        "audio is not RIFF WAVE"
+    );
+
+    let mut offset = 12;
+    let mut format = None;
+    while offset + 8 <= wav.len() {
+        let id = &wav[offset..offset + 4];
+        let size = u32::from_le_bytes(wav[offset + 4..offset + 8].try_into()?) as usize;
+        let body = offset + 8;
+        let end = body.checked_add(size).context("chunk size overflows")?.min(wav.len());
+
+        match id {
+            b"fmt " => {
+                ensure!(end - body >= 16, "fmt chunk is too short");
+                let audio_format = u16::from_le_bytes([wav[body], wav[body + 1]]);
+                let bits_per_sample = u16::from_le_bytes([wav[body + 14], wav[body + 15]]);
+                format = Some((audio_format, bits_per_sample));
+            },
+            b"data" => {
+                match format {
+                    Some((PCM_FORMAT, BITS_PER_SAMPLE)) => {},
+                    Some((audio_format, bits_per_sample)) => {
+                        bail!("unsupported wav format {audio_format} with {bits_per_sample} bits per sample")
+                    },
+                    None => bail!("data chunk appears before fmt chunk"),
+                }
+                return Ok(body..body + (end - body) / 2 * 2);
+            },
+            _ => {},
+        }
+
+        // Chunks are aligned to even offsets
+        offset = end + (size & 1);
+    }
+
+    bail!("wav has no data chunk")
+}
+
+/// Root mean square of `samples` in range of 0.0 to 1.0.
+fn rms(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+
+    let sum = samples
+        .iter()
+        .map(|&sample| (sample as f64 / i16::MAX as f64).powi(2))
+        .sum::<f64>();
+    (sum / samples.len() as f64).sqrt() as f32
+}
+
+/// Absolute peak of `samples` in range of 0.0 to 1.0.
+fn peak(samples: &[i16]) -> f32 {
+    samples
+        .iter()
+        .map(|&sample| sample.unsigned_abs() as f32 / i16::MAX as f32)
+        .fold(0.0, f32::max)
+        .min(1.0)
+}
+
+fn gain(rms: f32, peak: f32, target_loudness: f32, volume: f32) -> f32 {
+    let normalization = match rms > 0.0 {
+        true => (10_f32.powf(target_loudness / 20.0) / rms).min(MAX_GAIN),
+        false => 1.0,
+    };
+    let gain = normalization * volume.max(0.0);
+
+    match peak * gain > PEAK_CEILING {
+        true => PEAK_CEILING / peak,
+        false => gain,
+    }
+}
+
+fn apply_gain(samples: &mut [i16], gain: f32) {
+    for sample in samples {
+        *sample = (*sample as f32 * gain).round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_gain, data_range, gain, normalize, peak, rms, MAX_GAIN, PEAK_CEILING};
+
+    fn wav(samples: &[i16]) -> Vec<u8> {
+        let data = samples.iter().flat_map(|sample| sample.to_le_bytes()).collect::<Vec<_>>();
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&16_u32.to_le_bytes());
+        wav.extend_from_slice(&1_u16.to_le_bytes());
+        wav.extend_from_slice(&1_u16.to_le_bytes());
+        wav.extend_from_slice(&24000_u32.to_le_bytes());
+        wav.extend_from_slice(&48000_u32.to_le_bytes());
+        wav.extend_from_slice(&2_u16.to_le_bytes());
+        wav.extend_from_slice(&16_u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(&data);
+        wav
+    }
+
+    fn samples(wav: &[u8]) -> Vec<i16> {
+        wav[44..]
+            .chunks_exact(2)
+            .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn rms_of_square_wave() {
+        assert_eq!(rms(&[]), 0.0);
+        assert_eq!(rms(&[0, 0, 0]), 0.0);
+        assert!((rms(&[16384, -16384, 16384, -16384]) - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn peak_of_samples() {
+        assert_eq!(peak(&[]), 0.0);
+        assert!((peak(&[100, -16384, 8192]) - 0.5).abs() < 1e-4);
+        assert_eq!(peak(&[i16::MIN]), 1.0);
+    }
+
+    #[test]
+    fn gain_reaches_target_loudness() {
+        // -20 dBFS is 0.1 in linear scale
+        assert!((gain(0.05, 0.1, -20.0, 1.0) - 2.0).abs() < 1e-4);
+        assert!((gain(0.2, 0.4, -20.0, 1.0) - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn gain_applies_volume() {
+        assert!((gain(0.05, 0.1, -20.0, 0.5) - 1.0).abs() < 1e-4);
+        assert_eq!(gain(0.05, 0.1, -20.0, -1.0), 0.0);
+    }
+
+    #[test]
+    fn gain_is_limited() {
+        assert_eq!(gain(0.001, 0.01, -20.0, 1.0), MAX_GAIN);
+        assert!((gain(0.05, 0.9, -20.0, 1.0) - PEAK_CEILING / 0.9).abs() < 1e-4);
+        assert_eq!(gain(0.0, 0.0, -20.0, 0.8), 0.8);
+    }
+
+    #[test]
+    fn apply_gain_saturates() {
+        let mut samples = [1000, -1000, 30000, -30000];
+        apply_gain(&mut samples, 2.0);
+        assert_eq!(samples, [2000, -2000, i16::MAX, i16::MIN]);
+    }
+
+    #[test]
+    fn normalize_wav() {
+        let normalized = normalize(&wav(&[1638, -1638, 1638, -1638]), -20.0, 1.0).unwrap();
+        assert_eq!(normalized.len(), 52);
+        assert_eq!(samples(&normalized), [3277, -3277, 3277, -3277]);
+
+        let normalized = normalize(&wav(&[1638, -1638, 1638, -1638]), -20.0, 0.5).unwrap();
+        assert_eq!(samples(&normalized), [1638, -1638, 1638, -1638]);
+    }
+
+    #[test]
+    fn data_range_skips_unknown_chunks() {
+        let mut with_list = wav(&[1, 2]);
+        with_list.splice(36..36, *b"LIST\x03\x00\x00\x00abc\x00");
+        assert_eq!(data_range(&with_list).unwrap(), 56..60);
+    }
+
+    #[test]
+    fn data_range_rejects_invalid_wav() {
+        assert!(data_range(b"RIFF").is_err());
+        assert!(data_range(b"OggS\x00\x00\x00\x00WAVE").is_err());
+
+        let mut float = wav(&[1, 2]);
+        float[20] = 3;
+        assert!(data_range(&float).is_err());
+    }
+}
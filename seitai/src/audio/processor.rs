@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use anyhow::Result;
 use songbird::{
     driver::Bitrate,
@@ -5,7 +7,18 @@ use songbird::{
 };
 use voicevox::Bytes;
 
-pub(crate) struct SongbirdAudioProcessor;
+use super::pcm;
+
+/// Bitrate songbird encodes audio with when it is not configured
+pub(crate) const DEFAULT_BITRATE: u32 = 128_000;
+/// Range of bitrate that Opus accepts
+pub(crate) const BITRATE_RANGE: RangeInclusive<u32> = 500..=512_000;
+
+pub(crate) struct SongbirdAudioProcessor {
+    /// Bitrate to compress audio with, which is `AUDIO_BITRATE` and not affected by `/config bitrate` because compressed
+    /// audio is cached and shared among guilds
+    pub(crate) bitrate: u32,
+}
 
 #[cfg_attr(test, mockall::automock(type Compressed = Vec<u8>; type Input = Vec<u8>; type Raw = Vec<u8>;))]
 pub(crate) trait AudioProcessor {
@@ -13,6 +26,7 @@ pub(crate) trait AudioProcessor {
     type Input;
     type Raw;
 
+    fn normalize(&self, raw: Self::Raw, volume: f32) -> Result<Self::Raw>;
     fn compress(&self, raw: Self::Raw) -> impl Future<Output = Result<Self::Compressed>> + Send;
     fn to_input(&self, compressed: &Self::Compressed) -> Self::Input;
 }
@@ -22,8 +36,13 @@ impl AudioProcessor for SongbirdAudioProcessor {
     type Input = Input;
     type Raw = Bytes;
 
+    fn normalize(&self, raw: Self::Raw, volume: f32) -> Result<Self::Raw> {
+        let normalized = pcm::normalize(&raw, pcm::TARGET_LOUDNESS, volume)?;
+        Ok(normalized.into())
+    }
+
     async fn compress(&self, raw: Self::Raw) -> Result<Self::Compressed> {
-        let compressed = Compressed::new(raw.into(), Bitrate::BitsPerSecond(128_000)).await?;
+        let compressed = Compressed::new(raw.into(), Bitrate::BitsPerSecond(self.bitrate as i32)).await?;
         let _ = compressed.raw.spawn_loader();
 
         Ok(compressed)
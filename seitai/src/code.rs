@@ -0,0 +1,155 @@
+use std::borrow::Cow;
+
+use crate::audio::cache::PredefinedUtterance;
+
+const FENCE: &str = "```";
+/// Maximum number of characters of inline code to be read as it is
+const MAX_INLINE_CODE_LENGTH: usize = 20;
+
+/// Names of languages of fenced code blocks to be read, keyed by tags written after fences
+const LANGUAGES: [(&[&str], &str); 24] = [
+    (&["bash", "sh", "shell", "zsh"], "シェル"),
+    (&["c"], "C"),
+    (&["cpp", "c++", "cc"], "C++"),
+    (&["cs", "csharp"], "C#"),
+    (&["css"], "CSS"),
+    (&["diff"], "差分"),
+    (&["go", "golang"], "Go"),
+    (&["html"], "HTML"),
+    (&["java"], "Java"),
+    (&["js", "javascript", "jsx"], "JavaScript"),
+    (&["json"], "JSON"),
+    (&["kotlin", "kt"], "Kotlin"),
+    (&["lua"], "Lua"),
+    (&["md", "markdown"], "マークダウン"),
+    (&["php"], "PHP"),
+    (&["ps1", "powershell"], "パワーシェル"),
+    (&["py", "python"], "Python"),
+    (&["rb", "ruby"], "Ruby"),
+    (&["rs", "rust"], "Rust"),
+    (&["sql"], "SQL"),
+    (&["swift"], "Swift"),
+    (&["toml"], "TOML"),
+    (&["ts", "typescript", "tsx"], "TypeScript"),
+    (&["yaml", "yml"], "YAML"),
+];
+
+/// Replaces code in `text`. Fenced code blocks, including ones not closed until the end of `text`, are omitted with
+/// their languages if specified, and inline code is read as it is only if it is short.
+pub(crate) fn replace(text: &str) -> Cow<'_, str> {
+    if !text.contains('`') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut replaced = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('`') {
+        replaced.push_str(&rest[..start]);
+        let code = &rest[start..];
+
+        if let Some(body) = code.strip_prefix(FENCE) {
+            match body.find(FENCE) {
+                Some(end) => {
+                    replaced.push_str(&block(&body[..end]));
+                    rest = &body[end + FENCE.len()..];
+                },
+                None if body.trim().is_empty() => {
+                    replaced.push_str(code);
+                    rest = "";
+                },
+                None => {
+                    replaced.push_str(&block(body));
+                    rest = "";
+                },
+            }
+            continue;
+        }
+
+        // Inline code is enclosed in single or double backticks, and the latter can contain a backtick.
+        let delimiter = if code.starts_with("``") { "``" } else { "`" };
+        let body = &code[delimiter.len()..];
+        match body.find(delimiter) {
+            Some(end) if !body[..end].trim().is_empty() => {
+                replaced.push_str(&inline(&body[..end]));
+                rest = &body[end + delimiter.len()..];
+            },
+            _ => {
+                replaced.push_str(delimiter);
+                rest = body;
+            },
+        }
+    }
+    replaced.push_str(rest);
+
+    Cow::Owned(replaced)
+}
+
+/// "〇〇のコード省略" with a language if the first line of `body` is a tag of it.
+fn block(body: &str) -> String {
+    let language = body
+        .split_once('\n')
+        .map(|(tag, _)| tag.trim())
+        .filter(|tag| !tag.is_empty() && tag.chars().all(|c| c.is_ascii_alphanumeric() || "+#-_.".contains(c)));
+
+    match language {
+        Some(tag) => format!("\n{}の{}\n", language_name(tag), PredefinedUtterance::Code.as_ref()),
+        None => format!("\n{}\n", PredefinedUtterance::Code.as_ref()),
+    }
+}
+
+fn inline(body: &str) -> Cow<'_, str> {
+    let body = body.trim();
+    match body.contains('\n') || body.chars().count() > MAX_INLINE_CODE_LENGTH {
+        true => Cow::Owned(format!("\n{}\n", PredefinedUtterance::Code.as_ref())),
+        false => Cow::Borrowed(body),
+    }
+}
+
+fn language_name(tag: &str) -> &str {
+    let lowercase = tag.to_ascii_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|(tags, _)| tags.contains(&lowercase.as_str()))
+        .map_or(tag, |(_, name)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::replace;
+
+    #[test]
+    fn replace_fenced_block() {
+        assert_eq!(replace("見て\n```\nfoo\n```\nどう？"), "見て\n\nコード省略\n\nどう？");
+        assert_eq!(replace("```rust\nfn main() {}\n```"), "\nRustのコード省略\n");
+        assert_eq!(replace("```PY\nprint(1)\n```"), "\nPythonのコード省略\n");
+        assert_eq!(replace("```haskell\nmain = pure ()\n```"), "\nhaskellのコード省略\n");
+        assert_eq!(replace("```foo```"), "\nコード省略\n");
+        assert_eq!(replace("```not a tag\nfoo\n```"), "\nコード省略\n");
+    }
+
+    #[test]
+    fn replace_block_containing_backticks() {
+        assert_eq!(replace("```md\nuse `foo` here\n```後"), "\nマークダウンのコード省略\n後");
+        assert_eq!(replace("```js\nconst s = `${a}`;\n```"), "\nJavaScriptのコード省略\n");
+    }
+
+    #[test]
+    fn replace_unclosed_fence() {
+        assert_eq!(replace("これ\n```rust\nfn main() {"), "これ\n\nRustのコード省略\n");
+        assert_eq!(replace("```"), "```");
+    }
+
+    #[test]
+    fn replace_inline_code() {
+        assert_eq!(replace("`cargo build`して"), "cargo buildして");
+        assert_eq!(replace("`` a`b ``"), "a`b");
+        assert_eq!(replace("`let value = some_function(argument);`"), "\nコード省略\n");
+        assert_eq!(replace("a ` b"), "a ` b");
+        assert_eq!(replace("` `"), "` `");
+    }
+
+    #[test]
+    fn replace_without_code() {
+        assert!(matches!(replace("foo"), std::borrow::Cow::Borrowed("foo")));
+    }
+}
@@ -0,0 +1,362 @@
+use std::str::FromStr;
+
+use anyhow::{Context as _, Result};
+use database::{guild::Flag, PgPool};
+use serenity::{
+    all::{CommandOptionType, GuildId, Permissions},
+    builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateInteractionResponseMessage},
+    client::Context,
+    model::{application::CommandInteraction, Colour},
+};
+use songbird::driver::Bitrate;
+
+use crate::{
+    link::UrlMode,
+    permission::{self, DEFAULT_PERMISSION, MANAGED_COMMANDS, PERMISSIONS},
+    utils::{get_manager, respond},
+};
+
+use super::subcommand::Subcommand;
+
+#[tracing::instrument(skip_all)]
+pub(crate) async fn run(
+    context: &Context,
+    interaction: &CommandInteraction,
+    database: &PgPool,
+    default_bitrate: u32,
+) -> Result<()> {
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(());
+    };
+    let subcommand = interaction.data.options.first().context("cannot get /config subcommand")?;
+    let subcommand = Subcommand::from_command_data_option(subcommand).unwrap_or_default();
+
+    match subcommand.name {
+        "show" => {
+            let guild = database::guild::fetch_by_id(database, guild_id.get()).await?;
+            let requirements = database::command_permission::fetch_by_guild_id(database, guild_id.get()).await?;
+            let bitrate = match guild.bitrate {
+                Some(bitrate) => format!("{} kbps", bitrate / 1000),
+                None => format!("{} kbps（デフォルト）", default_bitrate / 1000),
+            };
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .title("設定")
+                    .field("音量", format!("{}%", (guild.volume * 100.0).round()), true)
+                    .field("ビットレート", bitrate, true)
+                    .field("URL", url_mode_name(&guild.url_mode), true)
+                    .field("連続する絵文字", if guild.collapse_emoji { "まとめる" } else { "まとめない" }, true)
+                    .field("読み上げ", reading(&guild), false)
+                    .field("管理コマンドの権限", permissions(&requirements), false)
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "volume" => {
+            let volume = subcommand
+                .options
+                .get("volume")
+                .and_then(|v| v.as_i64())
+                .context("cannot get volume from `/config volume` argument")?;
+
+            let guild = database::guild::update_volume(database, guild_id.get(), volume as f32 / 100.0).await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(format!("音量を{}%に設定しました。", (guild.volume * 100.0).round()))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "bitrate" => {
+            let bitrate = subcommand
+                .options
+                .get("bitrate")
+                .and_then(|v| v.as_i64())
+                .map(|kbps| kbps as u32 * 1000);
+
+            let guild = database::guild::update_bitrate(database, guild_id.get(), bitrate).await?;
+            let bitrate = guild.bitrate.map(|bitrate| bitrate as u32).unwrap_or(default_bitrate);
+            set_bitrate(context, guild_id, bitrate).await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(format!("ビットレートを {} kbps に設定しました。", bitrate / 1000))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "read" => {
+            let target = subcommand
+                .options
+                .get("target")
+                .and_then(|v| v.as_str())
+                .context("cannot get target from `/config read` argument")?;
+            let enabled = subcommand
+                .options
+                .get("enabled")
+                .and_then(|v| v.as_bool())
+                .context("cannot get enabled from `/config read` argument")?;
+            let (flag, name) = READ_TARGETS
+                .iter()
+                .find(|(_, value, _)| value == &target)
+                .map(|(flag, _, name)| (*flag, *name))
+                .with_context(|| format!("unknown target {target} of `/config read`"))?;
+
+            database::guild::update_flag(database, guild_id.get(), flag, enabled).await?;
+
+            let state = if enabled { "読み上げる" } else { "読み上げない" };
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(format!("{name}を{state}ように設定しました。"))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "url" => {
+            let mode = subcommand
+                .options
+                .get("mode")
+                .and_then(|v| v.as_str())
+                .context("cannot get mode from `/config url` argument")?;
+            let mode = UrlMode::from_str(mode).with_context(|| format!("unknown mode {mode} of `/config url`"))?;
+
+            let guild = database::guild::update_url_mode(database, guild_id.get(), mode.as_ref()).await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(format!("URL を「{}」に設定しました。", url_mode_name(&guild.url_mode)))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "emoji" => {
+            let collapse = subcommand
+                .options
+                .get("collapse")
+                .and_then(|v| v.as_bool())
+                .context("cannot get collapse from `/config emoji` argument")?;
+
+            database::guild::update_flag(database, guild_id.get(), Flag::CollapseEmoji, collapse).await?;
+
+            let state = if collapse { "1回だけ読む" } else { "すべて読む" };
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(format!("連続する同じ絵文字を{state}ように設定しました。"))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "permission" => {
+            let command = subcommand
+                .options
+                .get("command")
+                .and_then(|v| v.as_str())
+                .context("cannot get command from `/config permission` argument")?;
+            let role_id = subcommand.options.get("role").and_then(|v| v.as_role_id());
+            let permission = subcommand
+                .options
+                .get("permission")
+                .and_then(|v| v.as_str())
+                .map(|value| {
+                    PERMISSIONS
+                        .iter()
+                        .find(|(_, permission, _)| *permission == value)
+                        .map(|(permission, _, _)| permission.bits())
+                        .with_context(|| format!("unknown permission {value} of `/config permission`"))
+                })
+                .transpose()?;
+            let name = MANAGED_COMMANDS
+                .iter()
+                .find(|(name, _, _)| *name == command)
+                .map(|(_, _, name)| *name)
+                .with_context(|| format!("unknown command {command} of `/config permission`"))?;
+
+            let description = match (role_id, permission) {
+                (None, None) => {
+                    database::command_permission::delete(database, guild_id.get(), command).await?;
+                    format!("{name}の管理に必要な権限をデフォルトの{}に戻しました。", DEFAULT_PERMISSION.1)
+                },
+                (role_id, permission) => {
+                    let requirement = database::command_permission::create(
+                        database,
+                        guild_id.get(),
+                        command,
+                        role_id.map(|role_id| role_id.get()),
+                        permission,
+                    )
+                    .await?;
+                    format!("{name}の管理に {} が必要になるように設定しました。", permission::describe(&requirement))
+                },
+            };
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(description)
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
+/// Modes set by `/config url` with their names in Japanese
+const URL_MODES: [(UrlMode, &str); 3] = [
+    (UrlMode::Url, "URL と読む"),
+    (UrlMode::Domain, "サイト名を読む"),
+    (UrlMode::Silent, "読まない"),
+];
+
+fn url_mode_name(url_mode: &str) -> &'static str {
+    let mode = UrlMode::from_str(url_mode).unwrap_or_default();
+    URL_MODES
+        .iter()
+        .find(|(value, _)| *value == mode)
+        .map_or(URL_MODES[0].1, |(_, name)| name)
+}
+
+/// Flags toggled by `/config read` with their option values and names in Japanese
+const READ_TARGETS: [(Flag, &str, &str); 8] = [
+    (Flag::ReadReply, "reply", "返信先"),
+    (Flag::ReadSticker, "sticker", "スタンプ"),
+    (Flag::ReadEmbed, "embed", "埋め込み"),
+    (Flag::ReadPoll, "poll", "投票"),
+    (Flag::ReadForward, "forward", "転送されたメッセージ"),
+    (Flag::ReadQuote, "quote", "引用"),
+    (Flag::ReadFilename, "filename", "添付ファイル名"),
+    (Flag::AnnounceName, "name", "発言者の名前"),
+];
+
+fn reading(guild: &database::guild::Guild) -> String {
+    READ_TARGETS
+        .iter()
+        .map(|(flag, _, name)| format!("{name}: {}", if guild.flag(*flag) { "オン" } else { "オフ" }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn permissions(requirements: &[database::command_permission::CommandPermission]) -> String {
+    MANAGED_COMMANDS
+        .iter()
+        .map(|(command, _, name)| {
+            let requirement = requirements
+                .iter()
+                .find(|requirement| requirement.command == *command)
+                .map_or_else(|| format!("{}（デフォルト）", DEFAULT_PERMISSION.1), permission::describe);
+            format!("{name}: {requirement}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[rustfmt::skip]
+pub fn register() -> CreateCommand {
+    let show = CreateCommandOption::new(CommandOptionType::SubCommand, "show", "Shows settings of this server.")
+        .description_localized("ja", "このサーバーの設定を表示します。");
+
+    let volume = {
+        let volume = CreateCommandOption::new(CommandOptionType::Integer, "volume", "Volume in percent (0 - 200. default: 100)")
+            .name_localized("ja", "音量")
+            .description_localized("ja", "音量（%）。0 〜 200。デフォルトは 100")
+            .min_int_value(0)
+            .max_int_value(200)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "volume", "Sets volume of voice.")
+            .description_localized("ja", "読み上げの音量を設定します。")
+            .add_sub_option(volume)
+    };
+
+    let bitrate = {
+        let bitrate = CreateCommandOption::new(CommandOptionType::Integer, "bitrate", "Bitrate in kbps (8 - 384). Resets to default if omitted")
+            .name_localized("ja", "ビットレート")
+            .description_localized("ja", "ビットレート（kbps）。8 〜 384。省略するとデフォルトに戻す")
+            .min_int_value(8)
+            .max_int_value(384);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "bitrate", "Sets bitrate of voice.")
+            .description_localized("ja", "読み上げのビットレートを設定します。")
+            .add_sub_option(bitrate)
+    };
+
+    let read = {
+        let target = READ_TARGETS.iter().fold(
+            CreateCommandOption::new(CommandOptionType::String, "target", "What to be read aloud")
+                .name_localized("ja", "対象")
+                .description_localized("ja", "読み上げる対象")
+                .required(true),
+            |option, (_, value, name)| option.add_string_choice_localized(*value, *value, [("ja", *name)]),
+        );
+        let enabled = CreateCommandOption::new(CommandOptionType::Boolean, "enabled", "Whether to read aloud")
+            .name_localized("ja", "有効")
+            .description_localized("ja", "読み上げるかどうか")
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "read", "Sets whether to read replies, stickers, embeds, polls, forwarded messages, quotes, filenames or names of authors.")
+            .description_localized("ja", "返信先、スタンプ、埋め込み、投票、転送されたメッセージ、引用、添付ファイル名、発言者の名前を読み上げるかを設定します。")
+            .add_sub_option(target)
+            .add_sub_option(enabled)
+    };
+
+    let url = {
+        let mode = URL_MODES.iter().fold(
+            CreateCommandOption::new(CommandOptionType::String, "mode", "How to read URLs")
+                .name_localized("ja", "モード")
+                .description_localized("ja", "URL の読み方")
+                .required(true),
+            |option, (mode, name)| option.add_string_choice_localized(mode.as_ref(), mode.as_ref(), [("ja", *name)]),
+        );
+        CreateCommandOption::new(CommandOptionType::SubCommand, "url", "Sets how to read URLs. Discord message and invite links are always read specially.")
+            .description_localized("ja", "URL の読み方を設定します。Discord のメッセージリンクと招待リンクは常に専用の読み方になります。")
+            .add_sub_option(mode)
+    };
+
+    let emoji = {
+        let collapse = CreateCommandOption::new(CommandOptionType::Boolean, "collapse", "Whether to read the same emoji repeated in a row only once")
+            .name_localized("ja", "まとめる")
+            .description_localized("ja", "連続する同じ絵文字を1回だけ読むかどうか")
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "emoji", "Sets how emoji are read.")
+            .description_localized("ja", "絵文字の読み方を設定します。")
+            .add_sub_option(collapse)
+    };
+
+    let permission = {
+        let command = MANAGED_COMMANDS.iter().fold(
+            CreateCommandOption::new(CommandOptionType::String, "command", "Command to be restricted")
+                .name_localized("ja", "コマンド")
+                .description_localized("ja", "制限するコマンド")
+                .required(true),
+            |option, (command, _, name)| option.add_string_choice_localized(format!("/{command}"), *command, [("ja", *name)]),
+        );
+        let role = CreateCommandOption::new(CommandOptionType::Role, "role", "Role required to manage")
+            .name_localized("ja", "ロール")
+            .description_localized("ja", "管理に必要なロール");
+        let permission = PERMISSIONS.iter().fold(
+            CreateCommandOption::new(CommandOptionType::String, "permission", "Permission required to manage")
+                .name_localized("ja", "権限")
+                .description_localized("ja", "管理に必要な権限"),
+            |option, (_, value, name)| option.add_string_choice_localized(*value, *value, [("ja", *name)]),
+        );
+        CreateCommandOption::new(CommandOptionType::SubCommand, "permission", "Sets role or permission required to add or delete words and soundstickers. Either is enough, and omitting both requires Manage Server.")
+            .description_localized("ja", "単語やサウンドステッカーの登録・削除・復元に必要なロールか権限を設定します。どちらかがあれば実行でき、両方省略するとサーバー管理の権限が必要になります。")
+            .add_sub_option(command)
+            .add_sub_option(role)
+            .add_sub_option(permission)
+    };
+
+    CreateCommand::new("config")
+        .description("このサーバーでの読み上げの設定を行います。")
+        .default_member_permissions(Permissions::MANAGE_GUILD)
+        .set_options(vec![show, volume, bitrate, read, url, emoji, permission])
+}
+
+async fn set_bitrate(context: &Context, guild_id: GuildId, bitrate: u32) -> Result<()> {
+    let manager = get_manager(context).await?;
+    if let Some(call) = manager.get(guild_id) {
+        call.lock().await.set_bitrate(Bitrate::BitsPerSecond(bitrate as i32));
+    }
+
+    Ok(())
+}
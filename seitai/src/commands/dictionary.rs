@@ -1,37 +1,71 @@
 use anyhow::{bail, Context as _, Result};
+use database::{
+    dictionary_history::{DictionaryHistory, DictionaryWord},
+    PgPool,
+};
 use futures::{future, stream, StreamExt};
 use hashbrown::HashMap;
 use indexmap::IndexMap;
 use ordered_float::NotNan;
 use serenity::{
-    all::{CommandDataOptionValue, CommandOptionType},
-    builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateInteractionResponseMessage},
+    all::{CommandDataOptionValue, CommandOptionType, GuildId, User},
+    builder::{
+        CreateCommand,
+        CreateCommandOption,
+        CreateEmbed,
+        CreateInteractionResponseFollowup,
+        CreateInteractionResponseMessage,
+    },
     client::Context,
     model::{application::CommandInteraction, Colour},
 };
 use songbird::input::Input;
 use uuid::Uuid;
 use voicevox::dictionary::{
-    response::{DeleteUserDictWordResult, GetUserDictResult, PostUserDictWordResult, PutUserDictWordResult},
+    response::{
+        DeleteUserDictWordResult,
+        GetUserDictResult,
+        Item,
+        PostUserDictWordResult,
+        PutUserDictWordResult,
+        UserDict,
+    },
+    validation::PRIORITY_RANGE,
     Dictionary,
 };
 
 use crate::{
     audio::{cache::PredefinedUtterance, Audio, AudioRepository},
     character_converter::{to_full_width, to_half_width, to_katakana},
+    embed::{code_block_lines, FIELD_LIMIT},
     regex,
     speaker::Speaker,
-    utils::{get_manager, get_voicevox, normalize, respond},
+    utils::{get_dictionary_cache, get_manager, get_voicevox, normalize, respond},
 };
 
 use super::subcommand::Subcommand;
 
 const SYSTEM_SPEAKER: &str = "1";
+/// Kind of word whose reading is registered in seitai instead of the dictionary of VOICEVOX
+const ENGLISH_KIND: &str = "english";
+/// Number of changes shown by `/dictionary history`
+const HISTORY_LIMIT: u64 = 10;
+
+const OPERATION_ADD: &str = "add";
+const OPERATION_UPDATE: &str = "update";
+const OPERATION_DELETE: &str = "delete";
+const OPERATION_REVERT: &str = "revert";
+/// Operations on readings of English words, which are recorded along with the dictionary of VOICEVOX
+const OPERATION_ENGLISH_ADD: &str = "english_add";
+const OPERATION_ENGLISH_UPDATE: &str = "english_update";
+const OPERATION_ENGLISH_DELETE: &str = "english_delete";
+const OPERATION_ENGLISH_REVERT: &str = "english_revert";
 
 pub(crate) async fn run<Repository>(
     context: &Context,
     audio_repository: &Repository,
     interaction: &CommandInteraction,
+    database: &PgPool,
 ) -> Result<()>
 where
     Repository: AudioRepository<Input = Input> + Send + Sync,
@@ -69,12 +103,14 @@ where
             .collect::<HashMap<_, _>>();
         subcommand_options
             .entry("surface")
-            .and_replace_entry_with(|_key, word| {
-                let text = normalize(context, &guild_id, &users, &word);
-                Some(regex::EMOJI.replace_all(&text, ":$1:").into_owned())
-            });
+            .and_replace_entry_with(|_key, word| Some(normalize_surface(context, &guild_id, &users, &word)));
+        let kind = subcommand_options.remove("kind");
+        let is_english = kind.as_deref() == Some(ENGLISH_KIND);
 
         match option.name.as_str() {
+            "add" if is_english => {
+                register_english_reading(context, interaction, database, &subcommand_options).await?;
+            },
             "add" => {
                 subcommand_options.entry("accent_type").or_insert("0".to_string());
                 subcommand_options.entry("priority").or_insert("10".to_string());
@@ -85,8 +121,8 @@ where
                 let word = subcommand_options
                     .get("surface")
                     .context("there is no surface to make sure whether word is regsitered")?;
-                let uuid = match get_regsiterd(&dictionary, word).await {
-                    Ok(uuid) => uuid,
+                let registered = match get_registered_item(&dictionary, word).await {
+                    Ok(registered) => registered,
                     Err(error) => {
                         tracing::error!("failed to register {word} into dictionary\nError: {error:?}");
                         let message = CreateInteractionResponseMessage::new().embed(
@@ -100,10 +136,10 @@ where
                     },
                 };
 
-                if let Some(uuid) = uuid {
-                    update_word(context, interaction, &dictionary, &uuid, &subcommand_options).await?;
+                if let Some((uuid, item)) = registered {
+                    update_word(context, interaction, database, &dictionary, &uuid, &item, &subcommand_options).await?;
                 } else {
-                    register_word(context, interaction, &dictionary, &subcommand_options).await?;
+                    register_word(context, interaction, database, &dictionary, &subcommand_options).await?;
                 }
 
                 let manager = get_manager(context).await?;
@@ -114,12 +150,16 @@ where
                     continue;
                 };
 
+                let guild = database::guild::fetch_by_id(database, guild_id.get()).await?;
+                let volume = NotNan::new(guild.volume).or(NotNan::new(1.0)).unwrap();
+
                 let inputs = stream::iter([word, PredefinedUtterance::Registered.as_ref()])
                     .map(async |text| {
                         let audio = Audio {
                             text: text.to_string(),
                             speaker: SYSTEM_SPEAKER.to_string(),
                             speed: NotNan::new(Speaker::default_speed()).unwrap(),
+                            volume,
                         };
                         match audio_repository.get(audio).await {
                             Ok(input) => Some(input),
@@ -136,6 +176,75 @@ where
                     call.enqueue_input(input).await;
                 }
             },
+            "add-bulk" => {
+                let entries = subcommand_options
+                    .get("words")
+                    .context("there are no words to register in bulk")?;
+
+                // Registering many words may take longer than the 3 seconds Discord waits for the initial response
+                interaction.defer(&context.http).await?;
+
+                let embed = match register_words(context, interaction, database, &users, &dictionary, entries).await {
+                    Ok(embed) => embed,
+                    Err(error) => {
+                        tracing::error!("failed to register words into dictionary in bulk\nError: {error:?}");
+                        CreateEmbed::new()
+                            .title("単語の登録に失敗しました。")
+                            .field("詳細", format!("```\n{}\n```", error), false)
+                            .colour(Colour::RED)
+                    },
+                };
+                let followup = CreateInteractionResponseFollowup::new().embed(embed);
+                interaction.create_followup(&context.http, followup).await?;
+            },
+            "update" => {
+                let word = subcommand_options
+                    .get("surface")
+                    .context("there is no surface to update word")?
+                    .clone();
+                let registered = match get_registered_item(&dictionary, &word).await {
+                    Ok(registered) => registered,
+                    Err(error) => {
+                        tracing::error!("failed to update {word} in dictionary\nError: {error:?}");
+                        let message = CreateInteractionResponseMessage::new().embed(
+                            CreateEmbed::new()
+                                .title("単語の更新に失敗しました。")
+                                .field("詳細", format!("```\n{}\n```", error), false)
+                                .colour(Colour::RED),
+                        );
+                        respond(context, interaction, &message).await?;
+                        continue;
+                    },
+                };
+
+                let Some((uuid, item)) = registered else {
+                    let message = CreateInteractionResponseMessage::new().embed(
+                        CreateEmbed::new()
+                            .title("単語は登録されていません。")
+                            .field("単語", format!("```\n{}\n```", word), false)
+                            .colour(Colour::RED),
+                    );
+                    respond(context, interaction, &message).await?;
+                    continue;
+                };
+
+                // Fields that are not given are kept as registered.
+                subcommand_options
+                    .entry("pronunciation")
+                    .and_replace_entry_with(|_key, pronunciation| Some(to_katakana(&*pronunciation).into_owned()))
+                    .or_insert_with(|| item.pronunciation.clone());
+                subcommand_options
+                    .entry("accent_type")
+                    .or_insert_with(|| item.accent_type.to_string());
+                subcommand_options
+                    .entry("priority")
+                    .or_insert_with(|| item.priority.to_string());
+                if let Some(word_type) = item.word_type() {
+                    subcommand_options.entry("word_type").or_insert_with(|| word_type.to_string());
+                }
+
+                update_word(context, interaction, database, &dictionary, &uuid, &item, &subcommand_options).await?;
+            },
             // TODO: Paginate
             "list" => {
                 let response = match dictionary.list().await {
@@ -157,20 +266,58 @@ where
                     .values()
                     .map(|item| format!("{} -> {}", to_half_width(&item.surface), item.pronunciation))
                     .collect::<Vec<_>>();
-                let message = CreateInteractionResponseMessage::new().embed(
-                    CreateEmbed::new()
-                        .title("単語一覧")
-                        .description(format!("```\n{}\n```", words.join("\n")))
-                        .colour(Colour::FOOYOO),
-                );
+                let english_words = database::english_reading::fetch_all(database)
+                    .await?
+                    .into_iter()
+                    .map(|english_reading| format!("{} -> {}", english_reading.word, english_reading.reading))
+                    .collect::<Vec<_>>();
+
+                let mut embed = CreateEmbed::new()
+                    .title("単語一覧")
+                    .description(format!("```\n{}\n```", words.join("\n")))
+                    .colour(Colour::FOOYOO);
+                if !english_words.is_empty() {
+                    embed = embed.field("英単語の読み", format!("```\n{}\n```", english_words.join("\n")), false);
+                }
+                let message = CreateInteractionResponseMessage::new().embed(embed);
+                respond(context, interaction, &message).await?;
+            },
+            "delete" if is_english => {
+                let word = subcommand_options
+                    .get("surface")
+                    .context("there is no surface to delete english reading")?;
+
+                let embed = match database::english_reading::delete(database, &word.to_lowercase()).await? {
+                    Some(english_reading) => {
+                        let before = to_english_history_word(&english_reading.reading);
+                        record_history(
+                            database,
+                            interaction,
+                            &english_reading.word,
+                            OPERATION_ENGLISH_DELETE,
+                            Some(&before),
+                            None,
+                        )
+                        .await;
+                        CreateEmbed::new()
+                            .title("英単語の読みを削除しました。")
+                            .field("単語", format!("```\n{}\n```", word), false)
+                            .colour(Colour::FOOYOO)
+                    },
+                    None => CreateEmbed::new()
+                        .title("単語は登録されていません。")
+                        .field("単語", format!("```\n{}\n```", word), false)
+                        .colour(Colour::RED),
+                };
+                let message = CreateInteractionResponseMessage::new().embed(embed);
                 respond(context, interaction, &message).await?;
             },
             "delete" => {
                 let word = subcommand_options
                     .get("surface")
                     .context("there is no surface to delete word")?;
-                let uuid = match get_regsiterd(&dictionary, word).await {
-                    Ok(uuid) => uuid,
+                let registered = match get_registered_item(&dictionary, word).await {
+                    Ok(registered) => registered,
                     Err(error) => {
                         tracing::error!("failed to delete {word} in dictionary\nError: {error:?}");
                         let message = CreateInteractionResponseMessage::new().embed(
@@ -184,8 +331,8 @@ where
                     },
                 };
 
-                if let Some(uuid) = uuid {
-                    delete_word(context, interaction, &dictionary, &uuid, word).await?;
+                if let Some((uuid, item)) = registered {
+                    delete_word(context, interaction, database, &dictionary, &uuid, &item, word).await?;
                     continue;
                 }
 
@@ -197,6 +344,95 @@ where
                 );
                 respond(context, interaction, &message).await?;
             },
+            "history" => {
+                let word = subcommand_options
+                    .get("surface")
+                    .context("there is no surface to show history")?;
+                let histories = database::dictionary_history::fetch_by_surface(
+                    database,
+                    guild_id.get(),
+                    &to_full_width(word),
+                    HISTORY_LIMIT,
+                )
+                .await?;
+
+                let embed = match histories.is_empty() {
+                    true => CreateEmbed::new()
+                        .title("単語の履歴はありません。")
+                        .field("単語", format!("```\n{}\n```", word), false)
+                        .colour(Colour::RED),
+                    false => CreateEmbed::new()
+                        .title(format!("{word} の履歴"))
+                        .description("`/dictionary revert` に番号を指定すると、その変更後の状態に戻せます。")
+                        .fields(histories.iter().map(|history| {
+                            let format = match is_english_operation(&history.operation) {
+                                true => format_english_word,
+                                false => format_word,
+                            };
+                            (
+                                format!("#{} {}", history.id, operation_name(&history.operation)),
+                                format!(
+                                    "<t:{}:f> <@{}>\n{} → {}",
+                                    history.created_at,
+                                    history.user_id,
+                                    format(history.before().as_ref()),
+                                    format(history.after().as_ref()),
+                                ),
+                                false,
+                            )
+                        }))
+                        .colour(Colour::FOOYOO),
+                };
+                let message = CreateInteractionResponseMessage::new().embed(embed);
+                respond(context, interaction, &message).await?;
+            },
+            "revert" => {
+                let id = subcommand_options
+                    .get("id")
+                    .context("there is no id of history to revert")?
+                    .parse::<i64>()?;
+                let Some(history) = database::dictionary_history::fetch_by_id(database, guild_id.get(), id).await? else {
+                    let message = CreateInteractionResponseMessage::new().embed(
+                        CreateEmbed::new()
+                            .title("履歴が見つかりません。")
+                            .field("番号", format!("```\n{}\n```", id), false)
+                            .colour(Colour::RED),
+                    );
+                    respond(context, interaction, &message).await?;
+                    continue;
+                };
+
+                let is_english = is_english_operation(&history.operation);
+                let reverted = match is_english {
+                    true => revert_english_reading(interaction, database, &history).await,
+                    false => revert_word(interaction, database, &dictionary, &history).await,
+                };
+                let embed = match reverted {
+                    Ok(()) => {
+                        let state = match is_english {
+                            true => format_english_word(history.after().as_ref()),
+                            false => {
+                                invalidate_dictionary_words(context).await;
+                                format_word(history.after().as_ref())
+                            },
+                        };
+                        CreateEmbed::new()
+                            .title("単語を復元しました。")
+                            .field("単語", format!("```\n{}\n```", to_half_width(&history.surface)), false)
+                            .field("状態", state, false)
+                            .colour(Colour::FOOYOO)
+                    },
+                    Err(error) => {
+                        tracing::error!("failed to revert {} in dictionary\nError: {error:?}", history.surface);
+                        CreateEmbed::new()
+                            .title("単語の復元に失敗しました。")
+                            .field("詳細", format!("```\n{}\n```", error), false)
+                            .colour(Colour::RED)
+                    },
+                };
+                let message = CreateInteractionResponseMessage::new().embed(embed);
+                respond(context, interaction, &message).await?;
+            },
             _ => {
                 unreachable!();
             },
@@ -207,6 +443,11 @@ where
 
 #[rustfmt::skip]
 pub fn register() -> CreateCommand {
+    let kind = || CreateCommandOption::new(CommandOptionType::String, "kind", "Where word is registered (default: VOICEVOX dictionary)")
+        .name_localized("ja", "種類")
+        .description_localized("ja", "単語の登録先（デフォルトは VOICEVOX の辞書）")
+        .add_string_choice_localized("VOICEVOX dictionary", "voicevox", [("ja", "VOICEVOX の辞書")])
+        .add_string_choice_localized("English reading", ENGLISH_KIND, [("ja", "英単語の読み")]);
     let add = {
         let word = CreateCommandOption::new(CommandOptionType::String, "surface", "Word to be registered")
             .name_localized("ja", "単語")
@@ -240,6 +481,50 @@ pub fn register() -> CreateCommand {
             .add_sub_option(accent_type)
             .add_sub_option(word_type)
             .add_sub_option(priority)
+            .add_sub_option(kind())
+    };
+    let add_bulk = {
+        let words = CreateCommandOption::new(CommandOptionType::String, "words", "Words in `surface,pronunciation,accent_type` separated by lines or `;`")
+            .name_localized("ja", "単語")
+            .description_localized("ja", "`単語,ヨミ,音が下がる位置` を改行か `;` で区切って並べたもの（音が下がる位置は省略可）")
+            .max_length(6000)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "add-bulk", "Registers words into dictionary at once")
+            .description_localized("ja", "複数の単語をまとめて辞書に登録します")
+            .add_sub_option(words)
+    };
+    let update = {
+        let word = CreateCommandOption::new(CommandOptionType::String, "surface", "Word to be updated")
+            .name_localized("ja", "単語")
+            .description_localized("ja", "更新する単語")
+            .required(true);
+        let pronunciation = CreateCommandOption::new(CommandOptionType::String, "pronunciation", "Pronunciation")
+            .name_localized("ja", "ヨミ")
+            .description_localized("ja", "単語のヨミ（カタカナ）");
+        let accent_type = CreateCommandOption::new(CommandOptionType::Integer, "accent_type", "Position of accent core. See link on `/help directory`")
+            .name_localized("ja", "音が下がる位置")
+            .description_localized("ja", "音が下がる拍の位置。0 は途中で音が下がらず、n（n > 0）は n 拍目の直後に音が下がる。詳細は `/help dictionary` のリンクへ")
+            .min_int_value(0);
+        let word_type = CreateCommandOption::new(CommandOptionType::String, "word_type", "Category of word")
+            .name_localized("ja", "単語の種類")
+            .description_localized("ja", "単語の種類")
+            .add_string_choice_localized("PROPER_NOUN", "PROPER_NOUN", [("ja", "固有名詞")])
+            .add_string_choice_localized("COMMON_NOUN", "COMMON_NOUN", [("ja", "一般名詞")])
+            .add_string_choice_localized("VERB", "VERB", [("ja", "動詞")])
+            .add_string_choice_localized("ADJECTIVE", "ADJECTIVE", [("ja", "形容詞")])
+            .add_string_choice_localized("SUFFIX", "SUFFIX", [("ja", "接尾辞")]);
+        let priority = CreateCommandOption::new(CommandOptionType::Integer, "priority", "The higher number, the higher priority of word (0 - 10)")
+            .name_localized("ja", "優先度")
+            .description_localized("ja", "数字が大きいほど優先度が高くなる（0 〜 10）")
+            .min_int_value(0)
+            .max_int_value(10);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "update", "Updates word in dictionary. Omitted fields are kept")
+            .description_localized("ja", "辞書の単語を更新します。省略した項目はそのまま残ります")
+            .add_sub_option(word)
+            .add_sub_option(pronunciation)
+            .add_sub_option(accent_type)
+            .add_sub_option(word_type)
+            .add_sub_option(priority)
     };
     let list = CreateCommandOption::new(CommandOptionType::SubCommand, "list", "List registered words")
         .description_localized("ja", "登録されている単語を表示します。");
@@ -251,32 +536,398 @@ pub fn register() -> CreateCommand {
         CreateCommandOption::new(CommandOptionType::SubCommand, "delete", "Delete word in dictionary")
             .description_localized("ja", "単語を辞書から削除します")
             .add_sub_option(word)
+            .add_sub_option(kind())
+    };
+
+    let history = {
+        let word = CreateCommandOption::new(CommandOptionType::String, "surface", "Word whose changes are shown")
+            .name_localized("ja", "単語")
+            .description_localized("ja", "履歴を表示する単語")
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "history", "Shows who changed word and how")
+            .description_localized("ja", "単語を誰がどのように変更したかを表示します")
+            .add_sub_option(word)
+    };
+    let revert = {
+        let id = CreateCommandOption::new(CommandOptionType::Integer, "id", "Number of change shown by `/dictionary history`")
+            .name_localized("ja", "番号")
+            .description_localized("ja", "`/dictionary history` で表示される変更の番号")
+            .min_int_value(1)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "revert", "Reverts word to state after change")
+            .description_localized("ja", "単語を指定した変更の直後の状態に戻します")
+            .add_sub_option(id)
     };
 
     CreateCommand::new("dictionary")
         .description("Dictionary")
-        .set_options(vec![add, list, delete])
+        .set_options(vec![add, add_bulk, update, list, delete, history, revert])
 }
 
-async fn get_regsiterd(dictionary: &Dictionary, word: &str) -> Result<Option<Uuid>> {
+async fn get_registered_item(dictionary: &Dictionary, word: &str) -> Result<Option<(Uuid, Item)>> {
     let GetUserDictResult::Ok(list) = dictionary
         .list()
         .await
-        .context("failed to get dictionary to register word")?;
-    let uuids = list
+        .context("failed to get dictionary to find word")?;
+    find_item(list, word)
+}
+
+/// Finds the item of `word` in `list`, which must not be registered more than once.
+fn find_item(list: UserDict, word: &str) -> Result<Option<(Uuid, Item)>> {
+    let items = list
         .into_iter()
         .filter(|(_uuid, item)| item.surface == to_full_width(word))
         .collect::<IndexMap<_, _>>();
-    if uuids.len() > 1 {
+    if items.len() > 1 {
         bail!("{word} is registered in more than one");
     }
 
-    Ok(uuids.into_keys().next())
+    Ok(items.into_iter().next())
+}
+
+/// Normalizes mentions and emojis in `word` into the surface to be registered.
+fn normalize_surface(context: &Context, guild_id: &GuildId, users: &[User], word: &str) -> String {
+    let text = normalize(context, guild_id, users, &HashMap::new(), word);
+    regex::EMOJI.replace_all(&text, ":$1:").into_owned()
+}
+
+/// Registers or updates words of `entries` in lines of `surface,pronunciation[,accent_type]`, which can also be
+/// separated by `;` because slash command options cannot contain line breaks. Words are processed one by one, and the
+/// returned embed shows which of them failed and why.
+async fn register_words(
+    context: &Context,
+    interaction: &CommandInteraction,
+    database: &PgPool,
+    users: &[User],
+    dictionary: &Dictionary,
+    entries: &str,
+) -> Result<CreateEmbed> {
+    let mut registered = Vec::new();
+    let mut failures = Vec::new();
+    for entry in entries.split(['\n', ';']).map(str::trim).filter(|entry| !entry.is_empty()) {
+        match register_entry(context, interaction, database, users, dictionary, entry).await {
+            Ok(word) => registered.push(word),
+            Err(error) => failures.push(format!("{entry}: {error}")),
+        }
+    }
+    if registered.is_empty() && failures.is_empty() {
+        bail!("no words are given");
+    }
+    if !registered.is_empty() {
+        invalidate_dictionary_words(context).await;
+    }
+
+    let (title, colour) = match (registered.is_empty(), failures.is_empty()) {
+        (_, true) => ("単語を登録しました。", Colour::FOOYOO),
+        (true, false) => ("単語の登録に失敗しました。", Colour::RED),
+        (false, false) => ("一部の単語の登録に失敗しました。", Colour::RED),
+    };
+    let mut embed = CreateEmbed::new().title(title).colour(colour);
+    if !registered.is_empty() {
+        embed = embed.field("登録した単語", code_block_lines(&registered, FIELD_LIMIT), false);
+    }
+    if !failures.is_empty() {
+        embed = embed.field("登録できなかった単語", code_block_lines(&failures, FIELD_LIMIT), false);
+    }
+
+    Ok(embed)
+}
+
+/// Registers or updates a word of `entry` and returns it with its pronunciation.
+async fn register_entry(
+    context: &Context,
+    interaction: &CommandInteraction,
+    database: &PgPool,
+    users: &[User],
+    dictionary: &Dictionary,
+    entry: &str,
+) -> Result<String> {
+    let guild_id = interaction.guild_id.context("bulk registration is only available in guilds")?;
+    let fields = entry.split(',').map(str::trim).collect::<Vec<_>>();
+    let (surface, pronunciation, accent_type) = match fields[..] {
+        [surface, pronunciation] => (surface, pronunciation, "0"),
+        [surface, pronunciation, accent_type] => (surface, pronunciation, accent_type),
+        _ => bail!("`単語,ヨミ,音が下がる位置` の形式で入力してください"),
+    };
+    let surface = normalize_surface(context, &guild_id, users, surface);
+    let accent_type = accent_type
+        .parse()
+        .with_context(|| format!("音が下がる位置は 0 以上の整数で入力してください: {accent_type}"))?;
+
+    let GetUserDictResult::Ok(list) = dictionary
+        .list()
+        .await
+        .context("failed to get dictionary to find word")?;
+    let registered = find_item(list, &surface)?;
+    let before = registered.as_ref().map(|(_uuid, item)| to_history_word(item));
+    let after = DictionaryWord {
+        pronunciation: to_katakana(pronunciation).into_owned(),
+        accent_type,
+        word_type: before.as_ref().and_then(|before| before.word_type.clone()),
+        priority: before.as_ref().map_or(*PRIORITY_RANGE.end() as i32, |before| before.priority),
+    };
+
+    apply_word(dictionary, &surface, registered.as_ref().map(|(uuid, _item)| uuid), Some(&after)).await?;
+    let operation = match before {
+        Some(_) => OPERATION_UPDATE,
+        None => OPERATION_ADD,
+    };
+    record_history(database, interaction, &surface, operation, before.as_ref(), Some(&after)).await;
+
+    Ok(format!("{surface} -> {}", after.pronunciation))
+}
+
+/// Brings the word of `history` back into the state after the change, and records it as another change.
+async fn revert_word(
+    interaction: &CommandInteraction,
+    database: &PgPool,
+    dictionary: &Dictionary,
+    history: &DictionaryHistory,
+) -> Result<()> {
+    let registered = get_registered_item(dictionary, &history.surface).await?;
+    let before = registered.as_ref().map(|(_uuid, item)| to_history_word(item));
+    let after = history.after();
+
+    apply_word(
+        dictionary,
+        &history.surface,
+        registered.as_ref().map(|(uuid, _item)| uuid),
+        after.as_ref(),
+    )
+    .await?;
+    record_history(database, interaction, &history.surface, OPERATION_REVERT, before.as_ref(), after.as_ref()).await;
+
+    Ok(())
+}
+
+/// Brings the reading of an English word in `history` back into the state after the change, and records it as another
+/// change.
+async fn revert_english_reading(
+    interaction: &CommandInteraction,
+    database: &PgPool,
+    history: &DictionaryHistory,
+) -> Result<()> {
+    let word = to_half_width(&history.surface).to_lowercase();
+    let before = database::english_reading::fetch_by_words(database, &[word.as_str()])
+        .await?
+        .pop()
+        .map(|english_reading| to_english_history_word(&english_reading.reading));
+    let after = history.after();
+
+    match &after {
+        Some(after) => {
+            database::english_reading::create(database, &*word, &*after.pronunciation).await?;
+        },
+        None => {
+            database::english_reading::delete(database, &word).await?;
+        },
+    }
+    record_history(database, interaction, &word, OPERATION_ENGLISH_REVERT, before.as_ref(), after.as_ref()).await;
+
+    Ok(())
+}
+
+/// Makes `surface` in the dictionary into `word`, where the registered one is deleted if `word` is absent.
+async fn apply_word(
+    dictionary: &Dictionary,
+    surface: &str,
+    registered: Option<&Uuid>,
+    word: Option<&DictionaryWord>,
+) -> Result<()> {
+    let Some(word) = word else {
+        let Some(uuid) = registered else {
+            return Ok(());
+        };
+        return match dictionary.delete_word(uuid).await? {
+            DeleteUserDictWordResult::NoContent => Ok(()),
+            DeleteUserDictWordResult::UnprocessableEntity(error) => bail!(error.detail),
+        };
+    };
+
+    let accent_type = word.accent_type.to_string();
+    let priority = word.priority.to_string();
+    let mut parameters = vec![
+        ("surface", surface),
+        ("pronunciation", word.pronunciation.as_str()),
+        ("accent_type", accent_type.as_str()),
+        ("priority", priority.as_str()),
+    ];
+    parameters.extend(word.word_type.as_deref().map(|word_type| ("word_type", word_type)));
+
+    let detail = match registered {
+        Some(uuid) => match dictionary.update_word(uuid, &parameters).await? {
+            PutUserDictWordResult::NoContent => None,
+            PutUserDictWordResult::UnprocessableEntity(error) => Some(error.detail),
+        },
+        None => match dictionary.register_word(&parameters).await? {
+            PostUserDictWordResult::Ok(_id) => None,
+            PostUserDictWordResult::UnprocessableEntity(error) => Some(error.detail),
+        },
+    };
+    if let Some(detail) = detail {
+        bail!(detail);
+    }
+
+    Ok(())
+}
+
+/// Records a change of `surface` by the user of `interaction`. A failure is only logged not to fail the change that
+/// has already been made.
+async fn record_history(
+    database: &PgPool,
+    interaction: &CommandInteraction,
+    surface: &str,
+    operation: &str,
+    before: Option<&DictionaryWord>,
+    after: Option<&DictionaryWord>,
+) {
+    let Some(guild_id) = interaction.guild_id else {
+        return;
+    };
+    let surface = to_full_width(surface);
+    if let Err(error) = database::dictionary_history::create(
+        database,
+        guild_id.get(),
+        interaction.user.id.get(),
+        &*surface,
+        operation,
+        before,
+        after,
+    )
+    .await
+    {
+        tracing::error!("failed to record history of {surface} in dictionary\nError: {error:?}");
+    }
+}
+
+fn to_history_word(item: &Item) -> DictionaryWord {
+    DictionaryWord {
+        pronunciation: item.pronunciation.clone(),
+        accent_type: i32::try_from(item.accent_type).unwrap_or(i32::MAX),
+        word_type: item.word_type().map(str::to_string),
+        priority: i32::try_from(item.priority).unwrap_or(i32::MAX),
+    }
+}
+
+/// Converts the reading of an English word into a word to be recorded, which has no accent or priority.
+fn to_english_history_word(reading: &str) -> DictionaryWord {
+    DictionaryWord {
+        pronunciation: reading.to_string(),
+        accent_type: 0,
+        word_type: None,
+        priority: 0,
+    }
+}
+
+/// Converts `parameters` sent to the engine into a word to be recorded, filling in defaults of the engine.
+fn parameters_to_word(parameters: &[(&str, &str)]) -> Option<DictionaryWord> {
+    let get = |name: &str| parameters.iter().find(|(key, _)| *key == name).map(|(_, value)| *value);
+    Some(DictionaryWord {
+        pronunciation: get("pronunciation")?.to_string(),
+        accent_type: get("accent_type").unwrap_or("0").parse().ok()?,
+        word_type: Some(get("word_type").unwrap_or("PROPER_NOUN").to_string()),
+        priority: get("priority").unwrap_or("5").parse().ok()?,
+    })
+}
+
+fn format_word(word: Option<&DictionaryWord>) -> String {
+    match word {
+        Some(word) => format!(
+            "{}（音が下がる位置 {}、優先度 {}）",
+            word.pronunciation, word.accent_type, word.priority
+        ),
+        None => "なし".to_string(),
+    }
+}
+
+fn format_english_word(word: Option<&DictionaryWord>) -> String {
+    match word {
+        Some(word) => word.pronunciation.clone(),
+        None => "なし".to_string(),
+    }
+}
+
+fn is_english_operation(operation: &str) -> bool {
+    matches!(
+        operation,
+        OPERATION_ENGLISH_ADD | OPERATION_ENGLISH_UPDATE | OPERATION_ENGLISH_DELETE | OPERATION_ENGLISH_REVERT
+    )
+}
+
+fn operation_name(operation: &str) -> &str {
+    match operation {
+        OPERATION_ADD => "登録",
+        OPERATION_UPDATE => "更新",
+        OPERATION_DELETE => "削除",
+        OPERATION_REVERT => "復元",
+        OPERATION_ENGLISH_ADD => "英単語の読みの登録",
+        OPERATION_ENGLISH_UPDATE => "英単語の読みの更新",
+        OPERATION_ENGLISH_DELETE => "英単語の読みの削除",
+        OPERATION_ENGLISH_REVERT => "英単語の読みの復元",
+        operation => operation,
+    }
+}
+
+/// Registers reading of an English word, which is preferred to kanatrans in seitai.
+async fn register_english_reading(
+    context: &Context,
+    interaction: &CommandInteraction,
+    database: &PgPool,
+    property: &HashMap<&str, String>,
+) -> Result<()> {
+    let word = property
+        .get("surface")
+        .context("there is no surface to register english reading")?;
+    let pronunciation = property
+        .get("pronunciation")
+        .context("there is no pronunciation to register english reading")?;
+
+    let error = if !regex::ENGLISH_WORD.is_match(word) {
+        Some("英単語の読みはアルファベットの単語にのみ登録できます。")
+    } else if !regex::KANA.is_match(pronunciation) {
+        Some("ヨミはひらがなかカタカナで入力してください。")
+    } else {
+        None
+    };
+    if let Some(error) = error {
+        let message = CreateInteractionResponseMessage::new().embed(
+            CreateEmbed::new()
+                .title("単語の登録に失敗しました。")
+                .field("詳細", format!("```\n{}\n```", error), false)
+                .colour(Colour::RED),
+        );
+        return respond(context, interaction, &message).await;
+    }
+
+    let word = word.to_lowercase();
+    let before = database::english_reading::fetch_by_words(database, &[word.as_str()])
+        .await?
+        .pop()
+        .map(|english_reading| to_english_history_word(&english_reading.reading));
+    let english_reading =
+        database::english_reading::create(database, &*word, to_katakana(pronunciation.as_str())).await?;
+
+    let operation = match before {
+        Some(_) => OPERATION_ENGLISH_UPDATE,
+        None => OPERATION_ENGLISH_ADD,
+    };
+    let after = to_english_history_word(&english_reading.reading);
+    record_history(database, interaction, &word, operation, before.as_ref(), Some(&after)).await;
+
+    let message = CreateInteractionResponseMessage::new().embed(
+        CreateEmbed::new()
+            .title("英単語の読みを登録しました。")
+            .field("単語", format!("```\n{}\n```", english_reading.word), false)
+            .field("ヨミ", format!("```\n{}\n```", english_reading.reading), false)
+            .colour(Colour::FOOYOO),
+    );
+    respond(context, interaction, &message).await
 }
 
 async fn register_word(
     context: &Context,
     interaction: &CommandInteraction,
+    database: &PgPool,
     dictionary: &Dictionary,
     property: &HashMap<&str, String>,
 ) -> Result<()> {
@@ -307,6 +958,9 @@ async fn register_word(
 
     match response {
         PostUserDictWordResult::Ok(_id) => {
+            invalidate_dictionary_words(context).await;
+            let after = parameters_to_word(&parameters);
+            record_history(database, interaction, word, OPERATION_ADD, None, after.as_ref()).await;
             let message = CreateInteractionResponseMessage::new().embed(
                 CreateEmbed::new()
                     .title("単語を登録しました。")
@@ -334,8 +988,10 @@ async fn register_word(
 async fn update_word(
     context: &Context,
     interaction: &CommandInteraction,
+    database: &PgPool,
     dictionary: &Dictionary,
     uuid: &Uuid,
+    item: &Item,
     property: &HashMap<&str, String>,
 ) -> Result<()> {
     let word = property.get("surface").context("there is no surface to update word")?;
@@ -363,6 +1019,10 @@ async fn update_word(
 
     match response {
         PutUserDictWordResult::NoContent => {
+            invalidate_dictionary_words(context).await;
+            let after = parameters_to_word(&parameters);
+            let before = to_history_word(item);
+            record_history(database, interaction, word, OPERATION_UPDATE, Some(&before), after.as_ref()).await;
             let message = CreateInteractionResponseMessage::new().embed(
                 CreateEmbed::new()
                     .title("単語を更新しました。")
@@ -390,8 +1050,10 @@ async fn update_word(
 async fn delete_word(
     context: &Context,
     interaction: &CommandInteraction,
+    database: &PgPool,
     dictionary: &Dictionary,
     uuid: &Uuid,
+    item: &Item,
     word: &str,
 ) -> Result<()> {
     let response = match dictionary.delete_word(uuid).await {
@@ -410,6 +1072,8 @@ async fn delete_word(
 
     match response {
         DeleteUserDictWordResult::NoContent => {
+            invalidate_dictionary_words(context).await;
+            record_history(database, interaction, word, OPERATION_DELETE, Some(&to_history_word(item)), None).await;
             let message = CreateInteractionResponseMessage::new().embed(
                 CreateEmbed::new()
                     .title("単語を削除しました。")
@@ -432,3 +1096,11 @@ async fn delete_word(
 
     Ok(())
 }
+
+/// Discards the cached dictionary words so that the change is reflected in the next message.
+async fn invalidate_dictionary_words(context: &Context) {
+    match get_dictionary_cache(context).await {
+        Some(cache) => cache.invalidate().await,
+        None => tracing::error!("failed to get dictionary cache to invalidate"),
+    }
+}
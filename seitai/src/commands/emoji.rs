@@ -0,0 +1,154 @@
+use anyhow::{Context as _, Result};
+use database::PgPool;
+use serenity::{
+    all::{CommandOptionType, Permissions},
+    builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateInteractionResponseMessage},
+    client::Context,
+    model::{application::CommandInteraction, Colour},
+};
+
+use crate::{character_converter::to_katakana, regex, utils::respond};
+
+use super::subcommand::Subcommand;
+
+const MAX_READING_LENGTH: u16 = 32;
+
+#[tracing::instrument(skip_all)]
+pub(crate) async fn run(context: &Context, interaction: &CommandInteraction, database: &PgPool) -> Result<()> {
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(());
+    };
+    let subcommand = interaction.data.options.first().context("cannot get /emoji subcommand")?;
+    let subcommand = Subcommand::from_command_data_option(subcommand).unwrap_or_default();
+
+    match subcommand.name {
+        "set" => {
+            let emoji = get_emoji(&subcommand)?;
+            let reading = subcommand
+                .options
+                .get("reading")
+                .and_then(|v| v.as_str())
+                .context("cannot get reading from `/emoji set` argument")?
+                .trim();
+
+            let Some((name, id)) = parse_emoji(emoji) else {
+                return respond_error(context, interaction, "サーバーの絵文字を入力してください。").await;
+            };
+            if !regex::KANA.is_match(reading) {
+                return respond_error(context, interaction, "読み方はひらがなかカタカナで入力してください。").await;
+            }
+
+            let emoji_reading =
+                database::emoji_reading::create(database, guild_id.get(), id, name, to_katakana(reading)).await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(format!("{emoji} の読み方を「{}」に設定しました。", emoji_reading.reading))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "reset" => {
+            let emoji = get_emoji(&subcommand)?;
+            let Some((_, id)) = parse_emoji(emoji) else {
+                return respond_error(context, interaction, "サーバーの絵文字を入力してください。").await;
+            };
+
+            let description = match database::emoji_reading::delete(database, guild_id.get(), id).await? {
+                Some(_) => format!("{emoji} の読み方をリセットしました。"),
+                None => format!("{emoji} の読み方は設定されていません。"),
+            };
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(description)
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "list" => {
+            let emoji_readings = database::emoji_reading::fetch_by_guild_id(database, guild_id.get()).await?;
+            let description = match emoji_readings.is_empty() {
+                true => "絵文字の読み方は設定されていません。".to_string(),
+                false => emoji_readings
+                    .iter()
+                    .map(|emoji| format!("<:{}:{}> {}", emoji.name, emoji.emoji_id, emoji.reading))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            };
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .title("絵文字の読み方")
+                    .description(description)
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+pub fn register() -> CreateCommand {
+    let emoji = || CreateCommandOption::new(CommandOptionType::String, "emoji", "Custom emoji of this server")
+        .name_localized("ja", "絵文字")
+        .description_localized("ja", "このサーバーのカスタム絵文字")
+        .required(true);
+
+    let set = {
+        let reading = CreateCommandOption::new(CommandOptionType::String, "reading", "Reading of the emoji in hiragana or katakana")
+            .name_localized("ja", "読み方")
+            .description_localized("ja", "絵文字の読み方（ひらがなかカタカナ）")
+            .max_length(MAX_READING_LENGTH)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "set", "Sets how a custom emoji is read in this server.")
+            .description_localized("ja", "このサーバーでのカスタム絵文字の読み方を設定します。")
+            .add_sub_option(emoji())
+            .add_sub_option(reading)
+    };
+
+    let reset = CreateCommandOption::new(CommandOptionType::SubCommand, "reset", "Resets how a custom emoji is read in this server.")
+        .description_localized("ja", "このサーバーでのカスタム絵文字の読み方をリセットします。")
+        .add_sub_option(emoji());
+
+    let list = CreateCommandOption::new(CommandOptionType::SubCommand, "list", "Shows readings of custom emoji in this server.")
+        .description_localized("ja", "このサーバーで設定されているカスタム絵文字の読み方を表示します。");
+
+    CreateCommand::new("emoji")
+        .description("カスタム絵文字の読み方の設定を行います。")
+        .default_member_permissions(Permissions::MANAGE_GUILD)
+        .set_options(vec![set, reset, list])
+}
+
+fn get_emoji<'a>(subcommand: &Subcommand<'a>) -> Result<&'a str> {
+    subcommand
+        .options
+        .get("emoji")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .with_context(|| format!("cannot get emoji from `/emoji {}` argument", subcommand.name))
+}
+
+/// Returns name and id of a custom emoji if `value` consists only of it.
+fn parse_emoji(value: &str) -> Option<(&str, u64)> {
+    let captures = regex::EMOJI.captures(value)?;
+    if captures.get(0)?.as_str() != value {
+        return None;
+    }
+
+    let name = captures.get(1)?.as_str();
+    let id = captures[2].parse().ok()?;
+    Some((name, id))
+}
+
+async fn respond_error(context: &Context, interaction: &CommandInteraction, description: &str) -> Result<()> {
+    let message = CreateInteractionResponseMessage::new().embed(
+        CreateEmbed::new()
+            .description(description)
+            .colour(Colour::RED),
+    );
+    respond(context, interaction, &message).await
+}
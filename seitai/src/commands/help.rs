@@ -21,15 +21,28 @@ pub async fn run(context: &Context, interaction: &CommandInteraction) -> Result<
                         (
                             "add",
                             format!(
-                                "{}\n{}",
+                                "{}\n{}\n{}",
                                 "単語を辞書に追加します。任意で指定できる`音が下がる位置`については次のリンクを参照してください。",
-                                "https://tdmelodic.readthedocs.io/ja/latest/pages/introduction.html#representation-of-accent-nuclei-by-digits"
+                                "https://tdmelodic.readthedocs.io/ja/latest/pages/introduction.html#representation-of-accent-nuclei-by-digits",
+                                "`種類`に`英単語の読み`を指定すると、英単語の読み方を修正できます。"
                             )
                             .as_str(),
                             false,
                         ),
+                        (
+                            "add-bulk",
+                            "`単語,ヨミ,音が下がる位置`を改行か`;`で区切って並べ、複数の単語をまとめて登録します。",
+                            false,
+                        ),
+                        ("update", "登録済みの単語を更新します。指定しなかった項目はそのまま残ります。", false),
                         ("list", "単語一覧を表示します。", true),
                         ("delete", "単語を削除します。", true),
+                        ("history", "単語を誰がいつどのように変更したかを表示します。", false),
+                        (
+                            "revert",
+                            "`history`で表示される番号の変更直後の状態に単語を戻します。サーバーの管理権限が必要です。",
+                            false,
+                        ),
                     ]),
                 ),
                 _ => None,
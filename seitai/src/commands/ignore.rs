@@ -0,0 +1,206 @@
+use anyhow::{Context as _, Result};
+use database::PgPool;
+use serenity::{
+    all::{CommandOptionType, Permissions},
+    builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateInteractionResponseMessage},
+    client::Context,
+    model::{application::CommandInteraction, Colour},
+};
+
+use crate::{
+    ignore::{KIND_CHANNEL, KIND_ROLE, KIND_USER},
+    regex,
+    utils::respond,
+};
+
+use super::subcommand::Subcommand;
+
+#[tracing::instrument(skip_all)]
+pub(crate) async fn run(context: &Context, interaction: &CommandInteraction, database: &PgPool) -> Result<()> {
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(());
+    };
+    let subcommand = interaction.data.options.first().context("cannot get /ignore subcommand")?;
+    let subcommand = Subcommand::from_command_data_option(subcommand).unwrap_or_default();
+
+    let description = match subcommand.name {
+        kind @ (KIND_USER | KIND_ROLE | KIND_CHANNEL) => {
+            let (target_id, mention) = match kind {
+                KIND_USER => {
+                    let user_id = subcommand.options.get("user").and_then(|v| v.as_user_id());
+                    user_id.map(|user_id| (user_id.get(), format!("<@{user_id}>")))
+                },
+                KIND_ROLE => {
+                    let role_id = subcommand.options.get("role").and_then(|v| v.as_role_id());
+                    role_id.map(|role_id| (role_id.get(), format!("<@&{role_id}>")))
+                },
+                _ => {
+                    let channel_id = subcommand.options.get("channel").and_then(|v| v.as_channel_id());
+                    channel_id.map(|channel_id| (channel_id.get(), format!("<#{channel_id}>")))
+                },
+            }
+            .with_context(|| format!("cannot get {kind} from `/ignore {kind}` argument"))?;
+            let ignored = subcommand
+                .options
+                .get("ignored")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true);
+
+            match ignored {
+                true => match database::ignore::create(database, guild_id.get(), kind, target_id).await? {
+                    Some(_) => format!("{mention} のメッセージを読み上げないように設定しました。"),
+                    None => format!("{mention} のメッセージはすでに読み上げないように設定されています。"),
+                },
+                false => match database::ignore::delete(database, guild_id.get(), kind, target_id).await? {
+                    Some(_) => format!("{mention} のメッセージを読み上げるように設定しました。"),
+                    None => format!("{mention} のメッセージは読み上げないように設定されていません。"),
+                },
+            }
+        },
+        "bot" => {
+            let bot = subcommand
+                .options
+                .get("bot")
+                .and_then(|v| v.as_str())
+                .context("cannot get bot from `/ignore bot` argument")?
+                .trim();
+            let read = subcommand
+                .options
+                .get("read")
+                .and_then(|v| v.as_bool())
+                .context("cannot get read from `/ignore bot` argument")?;
+
+            let Some(bot_id) = parse_bot_id(bot) else {
+                let message = CreateInteractionResponseMessage::new().embed(
+                    CreateEmbed::new()
+                        .description("ボットかウェブフックのメンションか ID を入力してください。")
+                        .colour(Colour::RED),
+                );
+                return respond(context, interaction, &message).await;
+            };
+
+            match read {
+                true => {
+                    database::readable_bot::create(database, guild_id.get(), bot_id).await?;
+                    format!("<@{bot_id}> のメッセージを読み上げるように設定しました。")
+                },
+                false => {
+                    database::readable_bot::delete(database, guild_id.get(), bot_id).await?;
+                    format!("<@{bot_id}> のメッセージを読み上げないように設定しました。")
+                },
+            }
+        },
+        "list" => {
+            let ignores = database::ignore::fetch_by_guild_id(database, guild_id.get()).await?;
+            let readable_bots = database::readable_bot::fetch_by_guild_id(database, guild_id.get()).await?;
+
+            let ignores = ignores
+                .iter()
+                .map(|ignore| match ignore.kind.as_str() {
+                    KIND_ROLE => format!("<@&{}>", ignore.target_id),
+                    KIND_CHANNEL => format!("<#{}>", ignore.target_id),
+                    _ => format!("<@{}>", ignore.target_id),
+                })
+                .collect::<Vec<_>>();
+            let readable_bots = readable_bots
+                .iter()
+                .map(|bot| format!("<@{}>", bot.bot_id))
+                .collect::<Vec<_>>();
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .title("読み上げないユーザー・ロール・チャンネル")
+                    .field("読み上げない", join_or_none(&ignores), false)
+                    .field("読み上げるボット・ウェブフック", join_or_none(&readable_bots), false)
+                    .colour(Colour::FOOYOO),
+            );
+            return respond(context, interaction, &message).await;
+        },
+        _ => unreachable!(),
+    };
+
+    let message = CreateInteractionResponseMessage::new().embed(
+        CreateEmbed::new()
+            .description(description)
+            .colour(Colour::FOOYOO),
+    );
+    respond(context, interaction, &message).await
+}
+
+#[rustfmt::skip]
+pub fn register() -> CreateCommand {
+    let ignored = || CreateCommandOption::new(CommandOptionType::Boolean, "ignored", "Whether not to read aloud (default: true)")
+        .name_localized("ja", "読み上げない")
+        .description_localized("ja", "読み上げないかどうか。デフォルトは読み上げない");
+
+    let user = {
+        let user = CreateCommandOption::new(CommandOptionType::User, "user", "User whose messages are not read")
+            .name_localized("ja", "ユーザー")
+            .description_localized("ja", "メッセージを読み上げないユーザー")
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "user", "Sets whether to ignore messages of a user.")
+            .description_localized("ja", "ユーザーのメッセージを読み上げないかを設定します。")
+            .add_sub_option(user)
+            .add_sub_option(ignored())
+    };
+
+    let role = {
+        let role = CreateCommandOption::new(CommandOptionType::Role, "role", "Role whose members' messages are not read")
+            .name_localized("ja", "ロール")
+            .description_localized("ja", "メッセージを読み上げないロール")
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "role", "Sets whether to ignore messages of members with a role.")
+            .description_localized("ja", "ロールを持つメンバーのメッセージを読み上げないかを設定します。")
+            .add_sub_option(role)
+            .add_sub_option(ignored())
+    };
+
+    let channel = {
+        let channel = CreateCommandOption::new(CommandOptionType::Channel, "channel", "Channel whose messages are not read")
+            .name_localized("ja", "チャンネル")
+            .description_localized("ja", "メッセージを読み上げないチャンネル")
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "channel", "Sets whether to ignore messages in a channel.")
+            .description_localized("ja", "チャンネルのメッセージを読み上げないかを設定します。")
+            .add_sub_option(channel)
+            .add_sub_option(ignored())
+    };
+
+    let bot = {
+        let bot = CreateCommandOption::new(CommandOptionType::String, "bot", "Mention or ID of bot or webhook")
+            .name_localized("ja", "ボット")
+            .description_localized("ja", "ボットかウェブフックのメンションか ID")
+            .required(true);
+        let read = CreateCommandOption::new(CommandOptionType::Boolean, "read", "Whether to read aloud")
+            .name_localized("ja", "読み上げる")
+            .description_localized("ja", "読み上げるかどうか")
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "bot", "Sets whether to read messages of a bot or webhook, which are ignored by default.")
+            .description_localized("ja", "デフォルトでは読み上げないボットやウェブフックのメッセージを読み上げるかを設定します。")
+            .add_sub_option(bot)
+            .add_sub_option(read)
+    };
+
+    let list = CreateCommandOption::new(CommandOptionType::SubCommand, "list", "Shows ignored users, roles and channels and readable bots.")
+        .description_localized("ja", "読み上げないユーザー・ロール・チャンネルと読み上げるボットを表示します。");
+
+    CreateCommand::new("ignore")
+        .description("読み上げないユーザー・ロール・チャンネルの設定を行います。")
+        .default_member_permissions(Permissions::MANAGE_GUILD)
+        .set_options(vec![user, role, channel, bot, list])
+}
+
+/// Returns the ID of a bot or webhook given as a mention or an ID.
+fn parse_bot_id(value: &str) -> Option<u64> {
+    match regex::MENTION_USER.captures(value) {
+        Some(captures) if captures.get(0)?.as_str() == value => captures[1].parse().ok(),
+        _ => value.parse().ok(),
+    }
+}
+
+fn join_or_none(mentions: &[String]) -> String {
+    match mentions.is_empty() {
+        true => "なし".to_string(),
+        false => mentions.join("\n"),
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::{Context as _, Result};
+use database::PgPool;
 use hashbrown::HashMap;
 use ordered_float::NotNan;
 use serenity::{
@@ -7,7 +8,7 @@ use serenity::{
     client::Context,
     model::{application::CommandInteraction, Colour},
 };
-use songbird::input::Input;
+use songbird::{driver::Bitrate, input::Input};
 
 use crate::{
     audio::{cache::PredefinedUtterance, Audio, AudioRepository},
@@ -20,6 +21,8 @@ pub(crate) async fn run<Repository>(
     audio_repository: &Repository,
     connections: &mut HashMap<GuildId, ChannelId>,
     interaction: &CommandInteraction,
+    database: &PgPool,
+    bitrate: u32,
 ) -> Result<()>
 where
     Repository: AudioRepository<Input = Input> + Send + Sync,
@@ -60,6 +63,10 @@ where
     let join = { call.lock().await.join(connect_to).await? };
     join.await?;
 
+    let settings = database::guild::fetch_by_id(database, guild.id.get()).await?;
+    let bitrate = settings.bitrate.map(|bitrate| bitrate as u32).unwrap_or(bitrate);
+    call.lock().await.set_bitrate(Bitrate::BitsPerSecond(bitrate as i32));
+
     connections.insert(guild.id, interaction.channel_id);
 
     let message = CreateInteractionResponseMessage::new().embed(
@@ -76,6 +83,7 @@ where
             text: PredefinedUtterance::Connected.as_ref().to_string(),
             speaker: "1".to_string(),
             speed: NotNan::new(Speaker::default_speed()).unwrap(),
+            volume: NotNan::new(settings.volume).or(NotNan::new(1.0)).unwrap(),
         };
         let input = audio_repository
             .get(audio)
@@ -1,7 +1,14 @@
+pub mod config;
 pub mod dictionary;
+pub mod emoji;
 pub mod help;
+pub mod ignore;
 pub mod join;
 pub mod leave;
+pub mod ngword;
+pub mod nickname;
+pub mod replace;
+pub mod say;
 pub mod soundsticker;
 pub mod subcommand;
 pub mod voice;
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use anyhow::{Context as _, Result};
+use database::{ng_word::NgWord as DatabaseNgWord, PgPool};
+use serenity::{
+    all::{CommandOptionType, Permissions},
+    builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateInteractionResponseMessage},
+    client::Context,
+    model::{application::CommandInteraction, Colour},
+};
+
+use crate::{
+    embed::{inline_code, numbered_list},
+    ng_word::{Action, NgWord, MAX_NG_WORDS},
+    pattern::{self, MAX_PATTERN_LENGTH},
+    utils::{respond, respond_error},
+};
+
+use super::subcommand::Subcommand;
+
+/// Actions set by `/ngword add` with their names in Japanese
+const ACTIONS: [(Action, &str); 2] = [
+    (Action::Beep, "ピー音にする"),
+    (Action::Drop, "メッセージを読まない"),
+];
+
+#[tracing::instrument(skip_all)]
+pub(crate) async fn run(context: &Context, interaction: &CommandInteraction, database: &PgPool) -> Result<()> {
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(());
+    };
+    let subcommand = interaction.data.options.first().context("cannot get /ngword subcommand")?;
+    let subcommand = Subcommand::from_command_data_option(subcommand).unwrap_or_default();
+
+    match subcommand.name {
+        "add" => {
+            let pattern = subcommand
+                .options
+                .get("pattern")
+                .and_then(|v| v.as_str())
+                .context("cannot get pattern from `/ngword add` argument")?;
+            let is_regex = subcommand
+                .options
+                .get("regex")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default();
+            let action = subcommand
+                .options
+                .get("action")
+                .and_then(|v| v.as_str())
+                .map(Action::from_str)
+                .transpose()?
+                .unwrap_or_default();
+
+            if let Err(error) = NgWord::new(pattern, is_regex, action) {
+                let description = format!("NG ワードが正しくありません。\n```\n{error}\n```");
+                return respond_error(context, interaction, &description, true).await;
+            }
+
+            let ng_words = database::ng_word::fetch_by_guild_id(database, guild_id.get()).await?;
+            if ng_words.len() >= MAX_NG_WORDS {
+                let description = format!("NG ワードは{MAX_NG_WORDS}個までです。");
+                return respond_error(context, interaction, &description, true).await;
+            }
+
+            let ng_word = database::ng_word::create(database, guild_id.get(), pattern, is_regex, action.as_ref()).await?;
+
+            let message = CreateInteractionResponseMessage::new()
+                .embed(
+                    CreateEmbed::new()
+                        .title("NG ワードを追加しました。")
+                        .description(format_ng_word(ng_words.len() + 1, &ng_word))
+                        .colour(Colour::FOOYOO),
+                )
+                .ephemeral(true);
+            respond(context, interaction, &message).await?;
+        },
+        "list" => {
+            let ng_words = database::ng_word::fetch_by_guild_id(database, guild_id.get()).await?;
+            let description = match ng_words.is_empty() {
+                true => "NG ワードはありません。".to_string(),
+                false => numbered_list(&ng_words, format_ng_word),
+            };
+
+            let message = CreateInteractionResponseMessage::new()
+                .embed(
+                    CreateEmbed::new()
+                        .title("NG ワード")
+                        .description(description)
+                        .colour(Colour::FOOYOO),
+                )
+                .ephemeral(true);
+            respond(context, interaction, &message).await?;
+        },
+        "delete" => {
+            let number = subcommand
+                .options
+                .get("number")
+                .and_then(|v| v.as_i64())
+                .context("cannot get number from `/ngword delete` argument")?;
+            let index = usize::try_from(number)?.saturating_sub(1);
+
+            let ng_words = database::ng_word::fetch_by_guild_id(database, guild_id.get()).await?;
+            let Some(ng_word) = ng_words.get(index) else {
+                return respond_error(context, interaction, "NG ワードが見つかりません。", true).await;
+            };
+            database::ng_word::delete(database, guild_id.get(), ng_word.id).await?;
+
+            let message = CreateInteractionResponseMessage::new()
+                .embed(
+                    CreateEmbed::new()
+                        .title("NG ワードを削除しました。")
+                        .description(format_ng_word(index + 1, ng_word))
+                        .colour(Colour::FOOYOO),
+                )
+                .ephemeral(true);
+            respond(context, interaction, &message).await?;
+        },
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+pub fn register() -> CreateCommand {
+    let add = {
+        let pattern = CreateCommandOption::new(CommandOptionType::String, "pattern", "Text or regex not to be read")
+            .name_localized("ja", "ワード")
+            .description_localized("ja", "読み上げない文字列か正規表現。大文字と小文字は区別しない")
+            .max_length(MAX_PATTERN_LENGTH)
+            .required(true);
+        let regex = CreateCommandOption::new(CommandOptionType::Boolean, "regex", "Whether pattern is regex (default: false)")
+            .name_localized("ja", "正規表現")
+            .description_localized("ja", "ワードを正規表現として扱うか。デフォルトは false");
+        let action = ACTIONS.iter().fold(
+            CreateCommandOption::new(CommandOptionType::String, "action", "What to do with messages containing the word (default: beep)")
+                .name_localized("ja", "動作")
+                .description_localized("ja", "ワードを含むメッセージの扱い。デフォルトはピー音にする"),
+            |option, (action, name)| option.add_string_choice_localized(action.as_ref(), action.as_ref(), [("ja", *name)]),
+        );
+        CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Adds a word not to be read aloud.")
+            .description_localized("ja", "読み上げない NG ワードを追加します。")
+            .add_sub_option(pattern)
+            .add_sub_option(regex)
+            .add_sub_option(action)
+    };
+
+    let list = CreateCommandOption::new(CommandOptionType::SubCommand, "list", "Shows words not to be read aloud.")
+        .description_localized("ja", "NG ワードを表示します。");
+
+    let delete = {
+        let number = CreateCommandOption::new(CommandOptionType::Integer, "number", "Number of word to be deleted")
+            .name_localized("ja", "番号")
+            .description_localized("ja", "削除する NG ワードの番号")
+            .min_int_value(1)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "delete", "Deletes a word not to be read aloud.")
+            .description_localized("ja", "NG ワードを削除します。")
+            .add_sub_option(number)
+    };
+
+    CreateCommand::new("ngword")
+        .description("読み上げない NG ワードを設定します。")
+        .default_member_permissions(Permissions::MANAGE_GUILD)
+        .set_options(vec![add, list, delete])
+}
+
+/// Formats an NG word with its pattern hidden by a spoiler.
+fn format_ng_word(number: usize, ng_word: &DatabaseNgWord) -> String {
+    let action = ACTIONS
+        .iter()
+        .find(|(action, _)| action.as_ref() == ng_word.action)
+        .map_or(ACTIONS[0].1, |(_, name)| name);
+    format!(
+        "{number}. ||{}||{} → {action}",
+        inline_code(&ng_word.pattern),
+        pattern::describe_kind(ng_word.is_regex),
+    )
+}
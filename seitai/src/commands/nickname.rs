@@ -0,0 +1,90 @@
+use anyhow::{Context as _, Result};
+use database::PgPool;
+use serenity::{
+    all::CommandOptionType,
+    builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateInteractionResponseMessage},
+    client::Context,
+    model::{application::CommandInteraction, Colour},
+};
+
+use crate::{character_converter::to_katakana, regex, utils::respond};
+
+use super::subcommand::Subcommand;
+
+/// Maximum length of a reading, which is the same as a nickname in Discord
+const MAX_READING_LENGTH: u16 = 32;
+
+#[tracing::instrument(skip_all)]
+pub(crate) async fn run(context: &Context, interaction: &CommandInteraction, database: &PgPool) -> Result<()> {
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(());
+    };
+    let subcommand = interaction.data.options.first().context("cannot get /nickname subcommand")?;
+    let subcommand = Subcommand::from_command_data_option(subcommand).unwrap_or_default();
+
+    match subcommand.name {
+        "set" => {
+            let reading = subcommand
+                .options
+                .get("reading")
+                .and_then(|v| v.as_str())
+                .context("cannot get reading from `/nickname set` argument")?
+                .trim();
+
+            if !regex::KANA.is_match(reading) {
+                let message = CreateInteractionResponseMessage::new().embed(
+                    CreateEmbed::new()
+                        .description("読み方はひらがなかカタカナで入力してください。")
+                        .colour(Colour::RED),
+                );
+                respond(context, interaction, &message).await?;
+                return Ok(());
+            }
+
+            let nickname =
+                database::nickname::create(database, interaction.user.id.get(), guild_id.get(), to_katakana(reading))
+                    .await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(format!("名前の読み方を「{}」に設定しました。", nickname.reading))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "reset" => {
+            database::nickname::delete(database, interaction.user.id.get(), guild_id.get()).await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description("名前の読み方をリセットしました。")
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+pub fn register() -> CreateCommand {
+    let set = {
+        let reading = CreateCommandOption::new(CommandOptionType::String, "reading", "Reading of your name in hiragana or katakana")
+            .name_localized("ja", "読み方")
+            .description_localized("ja", "あなたの名前の読み方（ひらがなかカタカナ）")
+            .max_length(MAX_READING_LENGTH)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "set", "Sets how your name is read in this server.")
+            .description_localized("ja", "このサーバーでのあなたの名前の読み方を設定します。")
+            .add_sub_option(reading)
+    };
+
+    let reset = CreateCommandOption::new(CommandOptionType::SubCommand, "reset", "Resets how your name is read in this server.")
+        .description_localized("ja", "このサーバーでのあなたの名前の読み方をリセットします。");
+
+    CreateCommand::new("nickname")
+        .description("名前の読み方の設定を行います。")
+        .set_options(vec![set, reset])
+}
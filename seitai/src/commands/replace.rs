@@ -0,0 +1,332 @@
+use anyhow::{Context as _, Result};
+use database::{guild::Flag, replacement_rule::ReplacementRule, slang_override::SlangOverride, PgPool};
+use hashbrown::HashMap;
+use serenity::{
+    all::{CommandOptionType, Permissions},
+    builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter, CreateInteractionResponseMessage},
+    client::Context,
+    model::{application::CommandInteraction, Colour},
+};
+
+use crate::{
+    embed::{code_block, inline_code, join_lines, numbered_list, FIELD_LIMIT},
+    event_handler::{replace_message, ReplaceOptions},
+    pattern::{self, MAX_PATTERN_LENGTH},
+    rule::{Rule, MAX_REPLACEMENT_LENGTH, MAX_RULES},
+    slang::SLANGS,
+    transliterator::Transliterator,
+    utils::{respond, respond_error},
+};
+
+use super::subcommand::Subcommand;
+
+/// Maximum length of text given to `/replace test`
+const MAX_TEST_TEXT_LENGTH: u16 = 1000;
+
+#[tracing::instrument(skip_all)]
+pub(crate) async fn run(
+    context: &Context,
+    interaction: &CommandInteraction,
+    database: &PgPool,
+    transliterator: &Transliterator,
+) -> Result<()> {
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(());
+    };
+    let subcommand = interaction.data.options.first().context("cannot get /replace subcommand")?;
+    let subcommand = Subcommand::from_command_data_option(subcommand).unwrap_or_default();
+
+    match subcommand.name {
+        "add" => {
+            let pattern = subcommand
+                .options
+                .get("pattern")
+                .and_then(|v| v.as_str())
+                .context("cannot get pattern from `/replace add` argument")?;
+            let replacement = subcommand
+                .options
+                .get("replacement")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let is_regex = subcommand
+                .options
+                .get("regex")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default();
+
+            if let Err(error) = Rule::new(pattern, replacement, is_regex) {
+                let description = format!("置換ルールが正しくありません。\n```\n{error}\n```");
+                return respond_error(context, interaction, &description, false).await;
+            }
+
+            let rules = database::replacement_rule::fetch_by_guild_id(database, guild_id.get()).await?;
+            if rules.len() >= MAX_RULES {
+                let description = format!("置換ルールは{MAX_RULES}個までです。");
+                return respond_error(context, interaction, &description, false).await;
+            }
+
+            let rule = database::replacement_rule::create(database, guild_id.get(), pattern, replacement, is_regex).await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .title("置換ルールを追加しました。")
+                    .description(format_rule(rules.len() + 1, &rule))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "list" => {
+            let rules = database::replacement_rule::fetch_by_guild_id(database, guild_id.get()).await?;
+            let guild = database::guild::fetch_by_id(database, guild_id.get()).await?;
+            let slang_overrides = database::slang_override::fetch_by_guild_id(database, guild_id.get()).await?;
+            let description = match rules.is_empty() {
+                true => "置換ルールはありません。".to_string(),
+                false => numbered_list(&rules, format_rule),
+            };
+            let slang = if guild.expand_slang { "オン" } else { "オフ" };
+
+            let mut embed = CreateEmbed::new()
+                .title("置換ルール")
+                .description(description)
+                .footer(CreateEmbedFooter::new(format!("ネットスラングの置換: {slang}")))
+                .colour(Colour::FOOYOO);
+            if !slang_overrides.is_empty() {
+                let slang_overrides = slang_overrides.iter().map(format_slang_override).collect::<Vec<_>>();
+                let slang_overrides = join_lines(&slang_overrides, FIELD_LIMIT);
+                embed = embed.field("変更したネットスラング", slang_overrides, false);
+            }
+            let message = CreateInteractionResponseMessage::new().embed(embed);
+            respond(context, interaction, &message).await?;
+        },
+        "move" => {
+            let from = get_number(&subcommand, "from")?;
+            let to = get_number(&subcommand, "to")?;
+
+            let mut ids = database::replacement_rule::fetch_by_guild_id(database, guild_id.get())
+                .await?
+                .into_iter()
+                .map(|rule| rule.id)
+                .collect::<Vec<_>>();
+            if from >= ids.len() || to >= ids.len() {
+                return respond_error(context, interaction, "置換ルールが見つかりません。", false).await;
+            }
+
+            let id = ids.remove(from);
+            ids.insert(to, id);
+            let rules = database::replacement_rule::reorder(database, guild_id.get(), &ids).await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .title("置換ルールを並び替えました。")
+                    .description(numbered_list(&rules, format_rule))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "delete" => {
+            let number = get_number(&subcommand, "number")?;
+
+            let rules = database::replacement_rule::fetch_by_guild_id(database, guild_id.get()).await?;
+            let Some(rule) = rules.get(number) else {
+                return respond_error(context, interaction, "置換ルールが見つかりません。", false).await;
+            };
+            database::replacement_rule::delete(database, guild_id.get(), rule.id).await?;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .title("置換ルールを削除しました。")
+                    .description(format_rule(number + 1, rule))
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "test" => {
+            let text = subcommand
+                .options
+                .get("text")
+                .and_then(|v| v.as_str())
+                .context("cannot get text from `/replace test` argument")?;
+
+            let options = ReplaceOptions::fetch(context, database, guild_id, interaction.user.id).await?;
+            let replaced = replace_message(
+                context,
+                &options,
+                &[],
+                &HashMap::new(),
+                text,
+                transliterator,
+            )
+            .await;
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .field("置換前", code_block(text, FIELD_LIMIT), false)
+                    .field("置換後", code_block(&replaced, FIELD_LIMIT), false)
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "slang" => {
+            let enabled = subcommand
+                .options
+                .get("enabled")
+                .and_then(|v| v.as_bool())
+                .context("cannot get enabled from `/replace slang` argument")?;
+            let slang = subcommand.options.get("slang").and_then(|v| v.as_str());
+            let reading = subcommand.options.get("reading").and_then(|v| v.as_str());
+
+            let state = if enabled { "置換する" } else { "置換しない" };
+            let description = match (slang, reading) {
+                (None, _) => {
+                    database::guild::update_flag(database, guild_id.get(), Flag::ExpandSlang, enabled).await?;
+                    format!("草や888などのネットスラングを{state}ように設定しました。")
+                },
+                (Some(slang), _) if !enabled => {
+                    database::slang_override::create(database, guild_id.get(), slang, None).await?;
+                    format!("{slang} を{state}ように設定しました。")
+                },
+                (Some(slang), Some(reading)) => {
+                    database::slang_override::create(database, guild_id.get(), slang, Some(reading.to_string())).await?;
+                    format!("{slang} を「{reading}」に置換するように設定しました。")
+                },
+                (Some(slang), None) => {
+                    database::slang_override::delete(database, guild_id.get(), slang).await?;
+                    let reading = SLANGS
+                        .iter()
+                        .find(|default| default.name == slang)
+                        .map_or_else(String::new, |default| format!("「{}」に", default.reading));
+                    format!("{slang} をデフォルトの{reading}置換するように設定しました。")
+                },
+            };
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .description(description)
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+pub fn register() -> CreateCommand {
+    let add = {
+        let pattern = CreateCommandOption::new(CommandOptionType::String, "pattern", "Text or regex to be replaced")
+            .name_localized("ja", "置換前")
+            .description_localized("ja", "置換する文字列か正規表現")
+            .max_length(MAX_PATTERN_LENGTH)
+            .required(true);
+        let replacement = CreateCommandOption::new(CommandOptionType::String, "replacement", "Text to replace with. Removes matched text if omitted")
+            .name_localized("ja", "置換後")
+            .description_localized("ja", "置換後の文字列。省略すると削除する")
+            .max_length(MAX_REPLACEMENT_LENGTH);
+        let regex = CreateCommandOption::new(CommandOptionType::Boolean, "regex", "Whether pattern is regex (default: false)")
+            .name_localized("ja", "正規表現")
+            .description_localized("ja", "置換前を正規表現として扱うか。デフォルトは false");
+        CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Adds a replacement rule.")
+            .description_localized("ja", "置換ルールを追加します。")
+            .add_sub_option(pattern)
+            .add_sub_option(replacement)
+            .add_sub_option(regex)
+    };
+
+    let list = CreateCommandOption::new(CommandOptionType::SubCommand, "list", "Shows replacement rules in order of application.")
+        .description_localized("ja", "置換ルールを適用順に表示します。");
+
+    let r#move = {
+        let from = CreateCommandOption::new(CommandOptionType::Integer, "from", "Number of rule to be moved")
+            .name_localized("ja", "移動元")
+            .description_localized("ja", "移動する置換ルールの番号")
+            .min_int_value(1)
+            .required(true);
+        let to = CreateCommandOption::new(CommandOptionType::Integer, "to", "Number of rule after moved")
+            .name_localized("ja", "移動先")
+            .description_localized("ja", "移動後の番号")
+            .min_int_value(1)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "move", "Changes order of replacement rules.")
+            .description_localized("ja", "置換ルールの適用順を変更します。")
+            .add_sub_option(from)
+            .add_sub_option(to)
+    };
+
+    let delete = {
+        let number = CreateCommandOption::new(CommandOptionType::Integer, "number", "Number of rule to be deleted")
+            .name_localized("ja", "番号")
+            .description_localized("ja", "削除する置換ルールの番号")
+            .min_int_value(1)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "delete", "Deletes a replacement rule.")
+            .description_localized("ja", "置換ルールを削除します。")
+            .add_sub_option(number)
+    };
+
+    let test = {
+        let text = CreateCommandOption::new(CommandOptionType::String, "text", "Text to be replaced")
+            .name_localized("ja", "テキスト")
+            .description_localized("ja", "置換を試すテキスト")
+            .max_length(MAX_TEST_TEXT_LENGTH)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "test", "Shows how text is replaced before read aloud.")
+            .description_localized("ja", "読み上げ前にテキストがどう置換されるかを表示します。")
+            .add_sub_option(text)
+    };
+
+    let slang = {
+        let enabled = CreateCommandOption::new(CommandOptionType::Boolean, "enabled", "Whether to replace slang")
+            .name_localized("ja", "有効")
+            .description_localized("ja", "ネットスラングを置換するかどうか")
+            .required(true);
+        let slang = SLANGS.iter().fold(
+            CreateCommandOption::new(CommandOptionType::String, "slang", "Slang to be set. Sets all slang if omitted")
+                .name_localized("ja", "スラング")
+                .description_localized("ja", "設定するネットスラング。省略するとすべてのネットスラングを設定する"),
+            |option, slang| option.add_string_choice(format!("{} → {}", slang.name, slang.reading), slang.name),
+        );
+        let reading = CreateCommandOption::new(CommandOptionType::String, "reading", "Text to replace slang with. Resets to default if omitted")
+            .name_localized("ja", "置換後")
+            .description_localized("ja", "スラングの置換後の文字列。省略するとデフォルトに戻す")
+            .max_length(MAX_REPLACEMENT_LENGTH);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "slang", "Sets whether and how to replace internet slang such as 草 and 888 after replacement rules.")
+            .description_localized("ja", "置換ルールの後に草や888などのネットスラングを置換するかと、その置換後の文字列を設定します。")
+            .add_sub_option(enabled)
+            .add_sub_option(slang)
+            .add_sub_option(reading)
+    };
+
+    CreateCommand::new("replace")
+        .description("読み上げるテキストの置換ルールを設定します。")
+        .default_member_permissions(Permissions::MANAGE_GUILD)
+        .set_options(vec![add, list, r#move, delete, test, slang])
+}
+
+/// Returns 0-based index from 1-based number in `name` option.
+fn get_number(subcommand: &Subcommand, name: &str) -> Result<usize> {
+    let number = subcommand
+        .options
+        .get(name)
+        .and_then(|v| v.as_i64())
+        .with_context(|| format!("cannot get {name} from `/replace {}` argument", subcommand.name))?;
+
+    Ok(usize::try_from(number)?.saturating_sub(1))
+}
+
+fn format_slang_override(slang_override: &SlangOverride) -> String {
+    match &slang_override.reading {
+        Some(reading) => format!("{} → {reading}", slang_override.slang),
+        None => format!("{} → 置換しない", slang_override.slang),
+    }
+}
+
+fn format_rule(number: usize, rule: &ReplacementRule) -> String {
+    format!(
+        "{number}. {} → {}{}",
+        inline_code(&rule.pattern),
+        inline_code(&rule.replacement),
+        pattern::describe_kind(rule.is_regex),
+    )
+}
@@ -0,0 +1,181 @@
+use anyhow::{Context as _, Result};
+use database::PgPool;
+use ordered_float::NotNan;
+use serenity::{
+    all::{ChannelId, CommandDataOptionValue, CommandOptionType},
+    builder::{
+        AutocompleteChoice,
+        CreateAutocompleteResponse,
+        CreateCommand,
+        CreateCommandOption,
+        CreateEmbed,
+        CreateInteractionResponse,
+        CreateInteractionResponseMessage,
+    },
+    client::Context,
+    model::{application::CommandInteraction, Colour},
+};
+use songbird::input::Input;
+
+use crate::{
+    audio::{Audio, AudioRepository},
+    event_handler::{replace_message, ReplaceOptions},
+    speaker::Speaker,
+    transliterator::Transliterator,
+    utils::{get_manager, get_readings, get_user_voice, respond, respond_error},
+};
+
+use super::subcommand::SubcommandOptions;
+
+const NOT_CONNECTED: &str = "ボイスチャンネルに接続していません。";
+
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(skip_all)]
+pub(crate) async fn run<Repository>(
+    context: &Context,
+    interaction: &CommandInteraction,
+    audio_repository: &Repository,
+    database: &PgPool,
+    speaker: &Speaker,
+    transliterator: &Transliterator,
+) -> Result<()>
+where
+    Repository: AudioRepository<Input = Input> + Send + Sync,
+{
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(());
+    };
+    let options = SubcommandOptions::new(&interaction.data.options);
+    let text = options
+        .get("text")
+        .and_then(|v| v.as_str())
+        .context("cannot get text from `/say` argument")?;
+
+    let manager = get_manager(context).await?;
+    let Some(call) = manager.get(guild_id) else {
+        return respond_error(context, interaction, NOT_CONNECTED, true).await;
+    };
+    let channel_id_bot_at = {
+        let call = call.lock().await;
+        match (call.current_connection(), call.current_channel()) {
+            (Some(_), Some(channel_id)) => ChannelId::from(channel_id.0),
+            _ => return respond_error(context, interaction, NOT_CONNECTED, true).await,
+        }
+    };
+    let channel_id_user_at = guild_id.to_guild_cached(&context.cache).and_then(|guild| {
+        guild
+            .voice_states
+            .get(&interaction.user.id)
+            .and_then(|voice_state| voice_state.channel_id)
+    });
+    if channel_id_user_at != Some(channel_id_bot_at) {
+        let description = "読み上げ中のボイスチャンネルに参加してから実行してください。";
+        return respond_error(context, interaction, description, true).await;
+    }
+
+    let (speaker_id, speed) = get_user_voice(database, interaction.user.id).await?;
+    let (speaker_id, speed) = match options.get("voice").and_then(|v| v.as_i64()) {
+        Some(id) => {
+            let Ok(id) = u16::try_from(id) else {
+                return respond_error(context, interaction, "ボイスが見つかりません。", true).await;
+            };
+            if speaker.get_name(id).is_err() {
+                return respond_error(context, interaction, "ボイスが見つかりません。", true).await;
+            }
+            let speed = database::speaker::fetch_by_id(database, id)
+                .await?
+                .map(|speaker| speaker.speed)
+                .unwrap_or(Speaker::default_speed());
+            (id.to_string(), speed)
+        },
+        None => (speaker_id, speed),
+    };
+
+    let message = CreateInteractionResponseMessage::new()
+        .embed(
+            CreateEmbed::new()
+                .title("読み上げます。")
+                .description(text)
+                .colour(Colour::FOOYOO),
+        )
+        .ephemeral(true);
+    respond(context, interaction, &message).await?;
+
+    let options = ReplaceOptions::fetch(context, database, guild_id, interaction.user.id).await?;
+    let volume = options.settings.volume;
+    let mentions = interaction.data.resolved.users.values().cloned().collect::<Vec<_>>();
+    let readings = get_readings(database, guild_id, mentions.iter().map(|user| user.id)).await?;
+    let replaced = replace_message(
+        context,
+        &options,
+        &mentions,
+        &readings,
+        text,
+        transliterator,
+    )
+    .await;
+
+    let mut call = call.lock().await;
+    for text in replaced.split('\n') {
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let audio = Audio {
+            text: text.to_string(),
+            speaker: speaker_id.clone(),
+            speed: NotNan::new(speed).or(NotNan::new(Speaker::default_speed())).unwrap(),
+            volume: NotNan::new(volume).or(NotNan::new(1.0)).unwrap(),
+        };
+        match audio_repository.get(audio).await {
+            Ok(input) => {
+                call.enqueue_input(input).await;
+            },
+            Err(error) => {
+                tracing::error!("failed to get audio source\nError: {error:?}");
+            },
+        };
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+pub fn register() -> CreateCommand {
+    let text = CreateCommandOption::new(CommandOptionType::String, "text", "Text to be read aloud")
+        .name_localized("ja", "テキスト")
+        .description_localized("ja", "読み上げるテキスト")
+        .max_length(2000)
+        .required(true);
+    let voice = CreateCommandOption::new(CommandOptionType::Integer, "voice", "Voice to be used. Uses your voice if omitted")
+        .name_localized("ja", "ボイス")
+        .description_localized("ja", "使用するボイス。省略するとあなたのボイスを使用する")
+        .set_autocomplete(true);
+
+    CreateCommand::new("say")
+        .description("メッセージを投稿せずにテキストを読み上げます。")
+        .set_options(vec![text, voice])
+}
+
+pub(crate) async fn autocomplete(context: &Context, interaction: &CommandInteraction, speaker: &Speaker) -> Result<()> {
+    let options = SubcommandOptions::new(&interaction.data.options);
+
+    if let Some(CommandDataOptionValue::Autocomplete { value, .. }) = options.get("voice") {
+        let choices = speaker
+            .pairs()
+            .filter(|(name_pairs, _)| name_pairs.contains(value))
+            .map(|(name_pairs, id)| AutocompleteChoice::new(name_pairs.to_string(), id))
+            .take(25)
+            .collect::<Vec<_>>();
+        let autocomplete =
+            CreateInteractionResponse::Autocomplete(CreateAutocompleteResponse::new().set_choices(choices));
+        let error = format!("failed to create interaction response as autocomplete: {autocomplete:?}");
+        interaction
+            .create_response(&context.http, autocomplete)
+            .await
+            .context(error)?;
+    }
+
+    Ok(())
+}
@@ -1,27 +1,40 @@
-use anyhow::{Context as _, Result};
+use anyhow::{ensure, Context as _, Result};
 use database::PgPool;
+use hashbrown::HashMap;
 use serenity::{
     all::{CommandDataOptionValue, CommandOptionType},
     builder::{
         AutocompleteChoice,
+        CreateAttachment,
         CreateAutocompleteResponse,
         CreateCommand,
         CreateCommandOption,
         CreateEmbed,
         CreateInteractionResponse,
+        CreateInteractionResponseFollowup,
         CreateInteractionResponseMessage,
     },
     client::Context,
     model::{application::CommandInteraction, Colour},
 };
 
-use crate::{speaker::Speaker, utils::respond};
+use crate::{
+    audio::pcm,
+    event_handler::{replace_message, ReplaceOptions},
+    speaker::Speaker,
+    transliterator::Transliterator,
+    utils::{get_user_voice, get_voicevox, respond},
+};
+
+/// Maximum size of a file that bots can attach to a message
+const MAX_ATTACHMENT_SIZE: usize = 10 * 1024 * 1024;
 
 pub(crate) async fn run(
     context: &Context,
     interaction: &CommandInteraction,
     database: &PgPool,
     speaker: &Speaker,
+    transliterator: &Transliterator,
 ) -> Result<()> {
     let subcommand = interaction.data.options.first().context("cannot get subcommand")?;
     match subcommand.name.as_str() {
@@ -90,7 +103,54 @@ pub(crate) async fn run(
                     .colour(Colour::FOOYOO),
             );
             respond(context, interaction, &message).await?;
-        }
+        },
+        "romaji" => {
+            let enabled = get_subcommand_option(&subcommand.value, "enabled")
+                .context("cannot get enabled from `/voice romaji` argument")?
+                .as_bool()
+                .context(format!("{:?} is not boolean", subcommand.value))?;
+
+            let user = database::user::update_read_romaji(database, interaction.user.id.into(), enabled).await?;
+            let description = match user.read_romaji {
+                true => "ローマ字を日本語として読み上げます。",
+                false => "ローマ字を英語として読み上げます。",
+            };
+
+            let message = CreateInteractionResponseMessage::new().embed(
+                CreateEmbed::new()
+                    .title("ローマ字の読み方を変更しました。")
+                    .description(description)
+                    .colour(Colour::FOOYOO),
+            );
+            respond(context, interaction, &message).await?;
+        },
+        "render" => {
+            let text = get_subcommand_option(&subcommand.value, "text")
+                .context("cannot get text from `/voice render` argument")?
+                .as_str()
+                .context(format!("{:?} is not string", subcommand.value))?;
+
+            // Synthesis may take longer than the 3 seconds Discord waits for the initial response
+            interaction.defer(&context.http).await?;
+
+            let wav = match render(context, interaction, database, text, transliterator).await {
+                Ok(wav) => wav,
+                Err(error) => {
+                    tracing::error!("failed to render voice\nError: {error:?}");
+                    let followup = CreateInteractionResponseFollowup::new().embed(
+                        CreateEmbed::new()
+                            .description("音声の生成に失敗しました。")
+                            .colour(Colour::RED),
+                    );
+                    interaction.create_followup(&context.http, followup).await?;
+                    return Ok(());
+                },
+            };
+
+            let followup = CreateInteractionResponseFollowup::new()
+                .add_file(CreateAttachment::bytes(wav, "voice.wav"));
+            interaction.create_followup(&context.http, followup).await?;
+        },
         _ => unreachable!(),
     }
 
@@ -129,9 +189,30 @@ pub fn register() -> CreateCommand {
             .add_sub_option(speed)
     };
 
+    let romaji = {
+        let enabled = CreateCommandOption::new(CommandOptionType::Boolean, "enabled", "Whether romaji is read as Japanese (default: false)")
+            .name_localized("ja", "有効")
+            .description_localized("ja", "ローマ字を日本語として読み上げるか。デフォルトは false")
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "romaji", "Sets whether romaji in your message is read as Japanese.")
+            .description_localized("ja", "あなたのメッセージのローマ字を日本語として読み上げるかを設定します。")
+            .add_sub_option(enabled)
+    };
+
+    let render = {
+        let text = CreateCommandOption::new(CommandOptionType::String, "text", "Text to be synthesized")
+            .name_localized("ja", "テキスト")
+            .description_localized("ja", "音声にするテキスト")
+            .max_length(2000)
+            .required(true);
+        CreateCommandOption::new(CommandOptionType::SubCommand, "render", "Synthesizes text with your voice into an audio file.")
+            .description_localized("ja", "あなたのボイスでテキストを音声ファイルにします。")
+            .add_sub_option(text)
+    };
+
     CreateCommand::new("voice")
         .description("ボイスの設定を行います。")
-        .set_options(vec![r#use, reset, set_speed])
+        .set_options(vec![r#use, reset, set_speed, romaji, render])
 }
 
 pub(crate) async fn autocomplete(context: &Context, interaction: &CommandInteraction, speaker: &Speaker) -> Result<()> {
@@ -157,6 +238,39 @@ pub(crate) async fn autocomplete(context: &Context, interaction: &CommandInterac
     Ok(())
 }
 
+async fn render(
+    context: &Context,
+    interaction: &CommandInteraction,
+    database: &PgPool,
+    text: &str,
+    transliterator: &Transliterator,
+) -> Result<Vec<u8>> {
+    let (speaker_id, speed) = get_user_voice(database, interaction.user.id).await?;
+    let text = match interaction.guild_id {
+        Some(guild_id) => {
+            let options = ReplaceOptions::fetch(context, database, guild_id, interaction.user.id).await?;
+            replace_message(context, &options, &[], &HashMap::new(), text, transliterator).await
+        },
+        None => text.into(),
+    };
+
+    let audio_generator = {
+        let voicevox = get_voicevox(context).await.context("failed to get voicevox client")?;
+        let voicevox = voicevox.lock().await;
+        voicevox.audio_generator.clone()
+    };
+    let wav = audio_generator.generate(&speaker_id, &text, speed).await?;
+
+    let wav = pcm::normalize(&wav, pcm::TARGET_LOUDNESS, 1.0)?;
+    ensure!(
+        wav.len() <= MAX_ATTACHMENT_SIZE,
+        "rendered voice of {} bytes exceeds the attachment limit",
+        wav.len()
+    );
+
+    Ok(wav)
+}
+
 fn get_subcommand_option<'a>(value: &'a CommandDataOptionValue, name: &str) -> Option<&'a CommandDataOptionValue> {
     match value {
         CommandDataOptionValue::SubCommand(options) => options
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use futures::lock::Mutex;
+use voicevox::dictionary::{response::GetUserDictResult, Dictionary};
+
+use crate::character_converter::to_half_width;
+
+/// Interval to refresh the cache in case the dictionary is changed outside seitai
+pub(crate) const REFRESH_INTERVAL_SECS: u64 = 300;
+
+/// Surfaces of words registered in the dictionary of VOICEVOX, which are cached so that every message does not
+/// request the whole dictionary.
+#[derive(Debug)]
+pub(crate) struct DictionaryCache {
+    dictionary: Dictionary,
+    words: Mutex<Option<Vec<String>>>,
+}
+
+impl DictionaryCache {
+    pub(crate) fn new(dictionary: Dictionary) -> Self {
+        Self {
+            dictionary,
+            words: Mutex::new(None),
+        }
+    }
+
+    /// Returns the cached words, which are fetched from VOICEVOX if the cache is empty.
+    pub(crate) async fn words(&self) -> Result<Vec<String>> {
+        let mut words = self.words.lock().await;
+        if let Some(words) = &*words {
+            return Ok(words.clone());
+        }
+
+        let fetched = self.fetch().await?;
+        *words = Some(fetched.clone());
+        Ok(fetched)
+    }
+
+    /// Replaces the cached words with the current dictionary. The lock is held while fetching so that `invalidate`
+    /// called during the fetch is not overwritten by the words that may be stale.
+    pub(crate) async fn refresh(&self) -> Result<()> {
+        let mut words = self.words.lock().await;
+        *words = Some(self.fetch().await?);
+        Ok(())
+    }
+
+    /// Discards the cached words so that they are fetched again when needed next.
+    pub(crate) async fn invalidate(&self) {
+        *self.words.lock().await = None;
+    }
+
+    async fn fetch(&self) -> Result<Vec<String>> {
+        let GetUserDictResult::Ok(list) = self.dictionary.list().await?;
+        Ok(list
+            .values()
+            .map(|item| to_half_width(&item.surface).into_owned())
+            .collect())
+    }
+}
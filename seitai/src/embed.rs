@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+
+/// Maximum number of characters in the description of an embed
+pub(crate) const DESCRIPTION_LIMIT: usize = 4096;
+/// Maximum number of characters in the value of a field of an embed
+pub(crate) const FIELD_LIMIT: usize = 1024;
+
+const CODE_BLOCK_FENCE: &str = "```";
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Joins `lines` with line breaks within `limit` characters. Lines that do not fit are omitted and counted at the end.
+pub(crate) fn join_lines(lines: &[impl AsRef<str>], limit: usize) -> String {
+    let joined = lines.iter().map(AsRef::as_ref).collect::<Vec<_>>().join("\n");
+    if joined.chars().count() <= limit {
+        return joined;
+    }
+
+    // Keeps room for the number of omitted lines, which is at most the number of all lines
+    let reserved = format_omitted(lines.len()).chars().count() + 1;
+    let mut kept = Vec::new();
+    let mut length = 0;
+    for line in lines.iter().map(AsRef::as_ref) {
+        let added = line.chars().count() + usize::from(!kept.is_empty());
+        if length + added + reserved > limit {
+            break;
+        }
+        kept.push(line);
+        length += added;
+    }
+
+    let omitted = format_omitted(lines.len() - kept.len());
+    kept.push(&omitted);
+    kept.join("\n")
+}
+
+/// Formats `items` as a list numbered from 1 within the limit of the description of an embed.
+pub(crate) fn numbered_list<T>(items: &[T], format: impl Fn(usize, &T) -> String) -> String {
+    let lines = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| format(index + 1, item))
+        .collect::<Vec<_>>();
+    join_lines(&lines, DESCRIPTION_LIMIT)
+}
+
+/// Wraps `text` in a code block within `limit` characters, truncating the end of `text` if it is too long.
+pub(crate) fn code_block(text: &str, limit: usize) -> String {
+    let text = escape_backticks(text);
+    let limit = limit.saturating_sub(CODE_BLOCK_FENCE.len() * 2 + 2);
+    format!("{CODE_BLOCK_FENCE}\n{}\n{CODE_BLOCK_FENCE}", truncate(&text, limit))
+}
+
+/// Wraps `lines` in a code block within `limit` characters, omitting lines that do not fit.
+pub(crate) fn code_block_lines(lines: &[impl AsRef<str>], limit: usize) -> String {
+    let lines = lines.iter().map(|line| escape_backticks(line.as_ref())).collect::<Vec<_>>();
+    let limit = limit.saturating_sub(CODE_BLOCK_FENCE.len() * 2 + 2);
+    format!("{CODE_BLOCK_FENCE}\n{}\n{CODE_BLOCK_FENCE}", join_lines(&lines, limit))
+}
+
+/// Formats `text` as inline code, which may contain backticks.
+pub(crate) fn inline_code(text: &str) -> String {
+    match text.contains('`') {
+        true => format!("`` {} ``", escape_backticks(text)),
+        false => format!("`{text}`"),
+    }
+}
+
+/// Truncates `text` into `limit` characters, where the last one is replaced with an ellipsis.
+pub(crate) fn truncate(text: &str, limit: usize) -> Cow<'_, str> {
+    if text.chars().count() <= limit {
+        return Cow::Borrowed(text);
+    }
+
+    let mut truncated = text.chars().take(limit.saturating_sub(1)).collect::<String>();
+    truncated.push('…');
+    Cow::Owned(truncated)
+}
+
+/// Separates consecutive backticks not to close code.
+fn escape_backticks(text: &str) -> Cow<'_, str> {
+    match text.contains('`') {
+        true => Cow::Owned(text.replace('`', &format!("`{ZERO_WIDTH_SPACE}"))),
+        false => Cow::Borrowed(text),
+    }
+}
+
+fn format_omitted(count: usize) -> String {
+    format!("…他 {count} 件")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter;
+
+    use super::{code_block, code_block_lines, inline_code, join_lines, numbered_list, truncate};
+
+    #[test]
+    fn join_lines_within_limit() {
+        assert_eq!(join_lines(&["aaaa", "bbbb"], 9), "aaaa\nbbbb");
+        assert_eq!(join_lines(&["aaaa", "bbbb", "cccc"], 12), "aaaa\n…他 2 件");
+        assert_eq!(join_lines(&["aaaaaaaaaaaa", "b"], 10), "…他 2 件");
+
+        let lines = iter::repeat_n("あ".repeat(100), 50).collect::<Vec<_>>();
+        assert!(join_lines(&lines, 1024).chars().count() <= 1024);
+    }
+
+    #[test]
+    fn wrap_lines_in_code_block() {
+        assert_eq!(code_block_lines(&["foo", "bar"], 1024), "```\nfoo\nbar\n```");
+        assert_eq!(code_block_lines(&["a```b"], 1024), "```\na`\u{200B}`\u{200B}`\u{200B}b\n```");
+        assert_eq!(code_block_lines(&["foo", "bar", "baz"], 16), "```\n…他 3 件\n```");
+
+        let lines = iter::repeat_n("あ".repeat(100), 60).collect::<Vec<_>>();
+        assert!(code_block_lines(&lines, 1024).chars().count() <= 1024);
+    }
+
+    #[test]
+    fn number_items() {
+        assert_eq!(numbered_list(&["foo", "bar"], |number, item| format!("{number}. {item}")), "1. foo\n2. bar");
+
+        let items = iter::repeat_n("あ".repeat(100), 50).collect::<Vec<_>>();
+        assert!(numbered_list(&items, |number, item| format!("{number}. {item}")).chars().count() <= 4096);
+    }
+
+    #[test]
+    fn wrap_in_code_block() {
+        assert_eq!(code_block("foo", 1024), "```\nfoo\n```");
+        assert_eq!(code_block("foobar", 13), "```\nfoob…\n```");
+        assert_eq!(code_block("a```b", 1024), "```\na`\u{200B}`\u{200B}`\u{200B}b\n```");
+        assert!(code_block(&"あ".repeat(2000), 1024).chars().count() <= 1024);
+    }
+
+    #[test]
+    fn format_inline_code() {
+        assert_eq!(inline_code("foo"), "`foo`");
+        assert_eq!(inline_code("a`b"), "`` a`\u{200B}b ``");
+    }
+
+    #[test]
+    fn truncate_text() {
+        assert_eq!(truncate("あいうえお", 5), "あいうえお");
+        assert_eq!(truncate("あいうえお", 4), "あいう…");
+    }
+}
@@ -0,0 +1,368 @@
+use std::{borrow::Cow, ops::RangeInclusive};
+
+use anyhow::Result;
+use database::PgPool;
+use hashbrown::HashMap;
+use serenity::all::GuildId;
+
+use crate::regex;
+
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const SKIN_TONES: RangeInclusive<char> = '\u{1F3FB}'..='\u{1F3FF}';
+const COMBINING_KEYCAP: char = '\u{20E3}';
+const REGIONAL_INDICATORS: RangeInclusive<char> = '\u{1F1E6}'..='\u{1F1FF}';
+/// Blocks that consist mostly of emoji, such as Miscellaneous Symbols and Pictographs and Supplemental Symbols and
+/// Pictographs
+const PICTOGRAPHS: RangeInclusive<char> = '\u{1F000}'..='\u{1FAFF}';
+/// Blocks of symbols that are emoji only when a variation selector follows, such as Miscellaneous Symbols and Dingbats
+const SYMBOLS: [RangeInclusive<char>; 3] = ['\u{2300}'..='\u{23FF}', '\u{2600}'..='\u{27BF}', '\u{2B00}'..='\u{2BFF}'];
+/// Reading of a flag, which consists of two regional indicators
+const FLAG_READING: &str = "旗";
+/// Reading of emoji that are not in the reading table
+const GENERIC_READING: &str = "絵文字";
+
+/// Readings of Unicode emoji based on CLDR short names in Japanese, where variation selectors are omitted
+const READINGS: [(char, &str); 162] = [
+    ('😀', "にっこり笑う"),
+    ('😃', "口を開けて笑う"),
+    ('😄', "目を細めて笑う"),
+    ('😁', "歯を見せて笑う"),
+    ('😆', "目を閉じて笑う"),
+    ('😅', "冷や汗笑顔"),
+    ('🤣', "笑い転げる"),
+    ('😂', "うれし泣き"),
+    ('🙂', "少し笑う"),
+    ('🙃', "逆さまの顔"),
+    ('🫠', "溶ける顔"),
+    ('😉', "ウインク"),
+    ('😊', "にこにこ"),
+    ('😇', "天使の笑顔"),
+    ('🥰', "ハートに囲まれた笑顔"),
+    ('😍', "目がハート"),
+    ('🤩', "目が星"),
+    ('😘', "投げキッス"),
+    ('😋', "おいしい"),
+    ('😛', "舌を出した顔"),
+    ('😜', "ウインクして舌を出した顔"),
+    ('🤪', "おどけた顔"),
+    ('😝', "目を閉じて舌を出した顔"),
+    ('🤑', "お金の顔"),
+    ('🤗', "ハグ"),
+    ('🤭', "口に手を当てた顔"),
+    ('🫢', "目を開けて口に手を当てた顔"),
+    ('🤫', "静かに"),
+    ('🤔', "考え中"),
+    ('🫡', "敬礼"),
+    ('🤐', "チャック口"),
+    ('🤨', "眉を上げた顔"),
+    ('😐', "普通の顔"),
+    ('😑', "無表情"),
+    ('😶', "口のない顔"),
+    ('😏', "にやり"),
+    ('😒', "不満"),
+    ('🙄', "目を回す"),
+    ('😬', "しかめっ面"),
+    ('😌', "ほっとした顔"),
+    ('😔', "しょんぼり"),
+    ('😪', "眠い"),
+    ('🤤', "よだれ"),
+    ('😴', "寝顔"),
+    ('😷', "マスク顔"),
+    ('🤒', "熱がある顔"),
+    ('🤕', "けがをした顔"),
+    ('🤢', "吐き気"),
+    ('🤮', "嘔吐"),
+    ('🥵', "暑い顔"),
+    ('🥶', "寒い顔"),
+    ('🥴', "ふらふら"),
+    ('😵', "めまい"),
+    ('🤯', "頭爆発"),
+    ('🥳', "パーティー"),
+    ('😎', "サングラス"),
+    ('🤓', "オタク"),
+    ('🧐', "片眼鏡"),
+    ('😕', "困惑"),
+    ('😟', "心配"),
+    ('🙁', "少し不満"),
+    ('☹', "不満"),
+    ('😮', "口を開けた顔"),
+    ('😯', "びっくり"),
+    ('😲', "驚いた顔"),
+    ('😳', "赤面"),
+    ('🥺', "うるうる"),
+    ('🥹', "涙をこらえた顔"),
+    ('😨', "青ざめ"),
+    ('😰', "冷や汗"),
+    ('😥', "残念"),
+    ('😢', "泣き顔"),
+    ('😭', "大泣き"),
+    ('😱', "恐怖"),
+    ('😖', "困り顔"),
+    ('😣', "我慢"),
+    ('😞', "がっかり"),
+    ('😓', "冷や汗"),
+    ('😩', "疲れた"),
+    ('😫', "疲れ果てた"),
+    ('🥱', "あくび"),
+    ('😤', "勝ち誇った顔"),
+    ('😡', "ふくれっ面"),
+    ('😠', "怒った顔"),
+    ('🤬', "罵る顔"),
+    ('😈', "ニヤリとした悪魔"),
+    ('👿', "怒った悪魔"),
+    ('💀', "どくろ"),
+    ('💩', "うんち"),
+    ('🤡', "ピエロ"),
+    ('👻', "おばけ"),
+    ('👽', "宇宙人"),
+    ('🤖', "ロボット"),
+    ('😺', "笑う猫"),
+    ('😹', "うれし泣きの猫"),
+    ('🙈', "見ざる"),
+    ('🙉', "聞かざる"),
+    ('🙊', "言わざる"),
+    ('❤', "赤いハート"),
+    ('🧡', "オレンジのハート"),
+    ('💛', "黄色いハート"),
+    ('💚', "緑のハート"),
+    ('💙', "青いハート"),
+    ('💜', "紫のハート"),
+    ('🖤', "黒いハート"),
+    ('🤍', "白いハート"),
+    ('💔', "失恋"),
+    ('💕', "2つのハート"),
+    ('💖', "きらきらハート"),
+    ('💯', "100点"),
+    ('💢', "怒り"),
+    ('💥', "衝突"),
+    ('💦', "汗"),
+    ('💤', "ぐーぐー"),
+    ('👋', "手を振る"),
+    ('👌', "オーケー"),
+    ('✌', "ピース"),
+    ('🤞', "指をクロス"),
+    ('👍', "いいね"),
+    ('👎', "よくないね"),
+    ('👏', "拍手"),
+    ('🙌', "ばんざい"),
+    ('🙏', "お願い"),
+    ('💪', "力こぶ"),
+    ('👉', "右指差し"),
+    ('👈', "左指差し"),
+    ('👆', "上指差し"),
+    ('👇', "下指差し"),
+    ('👀', "目"),
+    ('🙆', "オーケーのポーズ"),
+    ('🙅', "ダメのポーズ"),
+    ('🙇', "お辞儀"),
+    ('🤷', "肩をすくめる"),
+    ('🤦', "顔に手を当てる"),
+    ('🔥', "火"),
+    ('✨', "きらきら"),
+    ('⭐', "星"),
+    ('🌟', "輝く星"),
+    ('🌸', "桜"),
+    ('🎉', "クラッカー"),
+    ('🎊', "くす玉"),
+    ('🎂', "バースデーケーキ"),
+    ('🎮', "ゲーム"),
+    ('🎵', "音符"),
+    ('🍣', "寿司"),
+    ('🍜', "ラーメン"),
+    ('🍺', "ビール"),
+    ('🍻', "乾杯"),
+    ('☕', "ホットドリンク"),
+    ('🐱', "猫の顔"),
+    ('🐶', "犬の顔"),
+    ('🐈', "猫"),
+    ('🐕', "犬"),
+    ('📢', "拡声器"),
+    ('💡', "電球"),
+    ('⏰', "目覚まし時計"),
+    ('✅', "チェックマーク"),
+    ('❌', "バツ印"),
+    ('⭕', "丸"),
+    ('❓', "疑問符"),
+    ('❗', "感嘆符"),
+    ('⚠', "警告"),
+];
+
+enum Token<'a> {
+    Text(&'a str),
+    Emoji(Cow<'a, str>),
+}
+
+/// Replaces custom emoji with readings set in the guild or `:name:` and Unicode emoji with their readings. When
+/// `collapse` is true, the same emoji repeated only with whitespaces between is read once.
+pub(crate) fn replace<'a>(text: &'a str, readings: &'a HashMap<u64, String>, collapse: bool) -> Cow<'a, str> {
+    let mut tokens = Vec::new();
+    let mut last = 0;
+    for captures in regex::EMOJI.captures_iter(text) {
+        let Some(whole) = captures.get(0) else {
+            continue;
+        };
+        tokenize_unicode(&text[last..whole.start()], &mut tokens);
+
+        let reading = captures[2]
+            .parse::<u64>()
+            .ok()
+            .and_then(|id| readings.get(&id))
+            .map_or_else(|| Cow::Owned(format!(":{}:", &captures[1])), |reading| Cow::Borrowed(reading.as_str()));
+        tokens.push(Token::Emoji(reading));
+        last = whole.end();
+    }
+    tokenize_unicode(&text[last..], &mut tokens);
+
+    if tokens.iter().all(|token| matches!(token, Token::Text(_))) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut replaced = String::with_capacity(text.len());
+    let mut previous: Option<&str> = None;
+    let mut is_adjacent = false;
+    for token in &tokens {
+        match token {
+            Token::Text(text) => {
+                if !text.trim().is_empty() {
+                    previous = None;
+                }
+                if !text.is_empty() {
+                    is_adjacent = false;
+                }
+                replaced.push_str(text);
+            },
+            Token::Emoji(reading) => {
+                if collapse && previous == Some(reading) {
+                    continue;
+                }
+                if is_adjacent {
+                    replaced.push(' ');
+                }
+                replaced.push_str(reading);
+                previous = Some(reading);
+                is_adjacent = true;
+            },
+        }
+    }
+
+    Cow::Owned(replaced)
+}
+
+/// Splits `text` into Unicode emoji and the other text. Emoji that are not in the reading table are read as flags,
+/// keycaps or generic emoji. Variation selectors, skin tones, keycap marks and sequences joined by zero width joiners
+/// that follow an emoji are regarded as a part of it.
+fn tokenize_unicode<'a>(text: &'a str, tokens: &mut Vec<Token<'a>>) {
+    let mut start = 0;
+    let mut characters = text.char_indices().peekable();
+    while let Some((index, character)) = characters.next() {
+        let rest = &text[index + character.len_utf8()..];
+        let reading = match READINGS.iter().find(|(emoji, _)| *emoji == character) {
+            Some((_, reading)) => Cow::Borrowed(*reading),
+            None if REGIONAL_INDICATORS.contains(&character) => {
+                characters.next_if(|(_, next)| REGIONAL_INDICATORS.contains(next));
+                Cow::Borrowed(FLAG_READING)
+            },
+            None if is_keycap(character, rest) => Cow::Borrowed(&text[index..index + character.len_utf8()]),
+            None if PICTOGRAPHS.contains(&character) => Cow::Borrowed(GENERIC_READING),
+            None if SYMBOLS.iter().any(|symbols| symbols.contains(&character)) && rest.starts_with(VARIATION_SELECTOR) => {
+                Cow::Borrowed(GENERIC_READING)
+            },
+            None => continue,
+        };
+
+        while let Some((_, next)) = characters.peek() {
+            match *next {
+                VARIATION_SELECTOR | COMBINING_KEYCAP => {},
+                next if SKIN_TONES.contains(&next) => {},
+                ZERO_WIDTH_JOINER => {
+                    characters.next();
+                },
+                _ => break,
+            }
+            characters.next();
+        }
+        let end = characters.peek().map_or(text.len(), |(index, _)| *index);
+
+        tokens.push(Token::Text(&text[start..index]));
+        tokens.push(Token::Emoji(reading));
+        start = end;
+    }
+    tokens.push(Token::Text(&text[start..]));
+}
+
+/// Returns whether `character` followed by `rest` is a keycap such as 1️⃣.
+fn is_keycap(character: char, rest: &str) -> bool {
+    matches!(character, '0'..='9' | '#' | '*')
+        && rest
+            .strip_prefix(VARIATION_SELECTOR)
+            .unwrap_or(rest)
+            .starts_with(COMBINING_KEYCAP)
+}
+
+/// Fetches readings of custom emoji set in a guild by emoji id.
+pub(crate) async fn fetch_readings(database: &PgPool, guild_id: GuildId) -> Result<HashMap<u64, String>> {
+    let readings = database::emoji_reading::fetch_by_guild_id(database, guild_id.get())
+        .await?
+        .into_iter()
+        .map(|emoji| (emoji.emoji_id as u64, emoji.reading))
+        .collect();
+
+    Ok(readings)
+}
+
+#[cfg(test)]
+mod tests {
+    use hashbrown::HashMap;
+
+    use super::replace;
+
+    #[test]
+    fn replace_unicode_emoji() {
+        let readings = HashMap::new();
+
+        assert_eq!(replace("やった🎉", &readings, false), "やったクラッカー");
+        assert_eq!(replace("❤️", &readings, false), "赤いハート");
+        assert_eq!(replace("👍🏽ok", &readings, false), "いいねok");
+        assert_eq!(replace("🤷‍♂️", &readings, false), "肩をすくめる");
+        assert_eq!(replace("🎉🎂", &readings, false), "クラッカー バースデーケーキ");
+    }
+
+    #[test]
+    fn replace_unknown_unicode_emoji() {
+        let readings = HashMap::new();
+
+        assert_eq!(replace("🦀", &readings, false), "絵文字");
+        assert_eq!(replace("🧑‍🦰です", &readings, false), "絵文字です");
+        assert_eq!(replace("☀️", &readings, false), "絵文字");
+        assert_eq!(replace("🇯🇵🇺🇸", &readings, false), "旗 旗");
+        assert_eq!(replace("1️⃣と#⃣", &readings, false), "1と#");
+        assert_eq!(replace("★1#", &readings, false), "★1#");
+    }
+
+    #[test]
+    fn replace_custom_emoji() {
+        let readings = HashMap::from([(2, "ネコ".to_string())]);
+
+        assert_eq!(replace("<:foo:1>", &readings, false), ":foo:");
+        assert_eq!(replace("<:cat:2>です", &readings, false), "ネコです");
+        assert_eq!(replace("<a:cat:2>", &readings, false), "ネコ");
+    }
+
+    #[test]
+    fn replace_collapse() {
+        let readings = HashMap::from([(2, "ネコ".to_string())]);
+
+        assert_eq!(replace("😂😂😂", &readings, false), "うれし泣き うれし泣き うれし泣き");
+        assert_eq!(replace("😂😂😂", &readings, true), "うれし泣き");
+        assert_eq!(replace("😂 😂", &readings, true), "うれし泣き ");
+        assert_eq!(replace("<:cat:2><:cat:2>", &readings, true), "ネコ");
+        assert_eq!(replace("😂あ😂", &readings, true), "うれし泣きあうれし泣き");
+        assert_eq!(replace("😂😭😂", &readings, true), "うれし泣き 大泣き うれし泣き");
+    }
+
+    #[test]
+    fn replace_without_emoji() {
+        assert!(matches!(replace("foo", &HashMap::new(), true), std::borrow::Cow::Borrowed("foo")));
+    }
+}
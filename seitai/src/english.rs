@@ -0,0 +1,543 @@
+/// Katakana of English words that are common as loanwords or whose spellings are far from their pronunciations, sorted
+/// by words
+const LOANWORDS: [(&str, &str); 172] = [
+    ("about", "アバウト"),
+    ("admin", "アドミン"),
+    ("all", "オール"),
+    ("and", "アンド"),
+    ("android", "アンドロイド"),
+    ("anime", "アニメ"),
+    ("any", "エニー"),
+    ("apple", "アップル"),
+    ("are", "アー"),
+    ("bad", "バッド"),
+    ("be", "ビー"),
+    ("best", "ベスト"),
+    ("birthday", "バースデー"),
+    ("bot", "ボット"),
+    ("but", "バット"),
+    ("by", "バイ"),
+    ("bye", "バイ"),
+    ("can", "キャン"),
+    ("channel", "チャンネル"),
+    ("chat", "チャット"),
+    ("code", "コード"),
+    ("coffee", "コーヒー"),
+    ("come", "カム"),
+    ("computer", "コンピューター"),
+    ("cool", "クール"),
+    ("day", "デイ"),
+    ("discord", "ディスコード"),
+    ("do", "ドゥー"),
+    ("download", "ダウンロード"),
+    ("eight", "エイト"),
+    ("email", "イーメール"),
+    ("english", "イングリッシュ"),
+    ("error", "エラー"),
+    ("everyone", "エブリワン"),
+    ("file", "ファイル"),
+    ("first", "ファースト"),
+    ("five", "ファイブ"),
+    ("for", "フォー"),
+    ("four", "フォー"),
+    ("free", "フリー"),
+    ("friend", "フレンド"),
+    ("from", "フロム"),
+    ("fun", "ファン"),
+    ("game", "ゲーム"),
+    ("get", "ゲット"),
+    ("git", "ギット"),
+    ("github", "ギットハブ"),
+    ("go", "ゴー"),
+    ("good", "グッド"),
+    ("google", "グーグル"),
+    ("great", "グレート"),
+    ("has", "ハズ"),
+    ("have", "ハブ"),
+    ("he", "ヒー"),
+    ("hello", "ハロー"),
+    ("here", "ヒア"),
+    ("hi", "ハイ"),
+    ("home", "ホーム"),
+    ("how", "ハウ"),
+    ("if", "イフ"),
+    ("image", "イメージ"),
+    ("in", "イン"),
+    ("internet", "インターネット"),
+    ("iphone", "アイフォーン"),
+    ("is", "イズ"),
+    ("it", "イット"),
+    ("japan", "ジャパン"),
+    ("japanese", "ジャパニーズ"),
+    ("java", "ジャバ"),
+    ("javascript", "ジャバスクリプト"),
+    ("just", "ジャスト"),
+    ("last", "ラスト"),
+    ("like", "ライク"),
+    ("link", "リンク"),
+    ("linux", "リナックス"),
+    ("live", "ライブ"),
+    ("login", "ログイン"),
+    ("logout", "ログアウト"),
+    ("love", "ラブ"),
+    ("mail", "メール"),
+    ("make", "メイク"),
+    ("many", "メニー"),
+    ("me", "ミー"),
+    ("member", "メンバー"),
+    ("minecraft", "マインクラフト"),
+    ("more", "モア"),
+    ("movie", "ムービー"),
+    ("much", "マッチ"),
+    ("music", "ミュージック"),
+    ("my", "マイ"),
+    ("new", "ニュー"),
+    ("news", "ニュース"),
+    ("next", "ネクスト"),
+    ("nice", "ナイス"),
+    ("night", "ナイト"),
+    ("nine", "ナイン"),
+    ("no", "ノー"),
+    ("not", "ノット"),
+    ("of", "オブ"),
+    ("offline", "オフライン"),
+    ("ok", "オーケー"),
+    ("okay", "オーケー"),
+    ("on", "オン"),
+    ("one", "ワン"),
+    ("online", "オンライン"),
+    ("or", "オア"),
+    ("out", "アウト"),
+    ("page", "ページ"),
+    ("party", "パーティー"),
+    ("password", "パスワード"),
+    ("people", "ピープル"),
+    ("play", "プレイ"),
+    ("player", "プレイヤー"),
+    ("please", "プリーズ"),
+    ("program", "プログラム"),
+    ("python", "パイソン"),
+    ("really", "リアリー"),
+    ("rust", "ラスト"),
+    ("see", "シー"),
+    ("server", "サーバー"),
+    ("seven", "セブン"),
+    ("she", "シー"),
+    ("site", "サイト"),
+    ("six", "シックス"),
+    ("so", "ソー"),
+    ("some", "サム"),
+    ("sorry", "ソーリー"),
+    ("steam", "スティーム"),
+    ("stream", "ストリーム"),
+    ("super", "スーパー"),
+    ("switch", "スイッチ"),
+    ("team", "チーム"),
+    ("ten", "テン"),
+    ("thank", "サンク"),
+    ("thanks", "サンクス"),
+    ("that", "ザット"),
+    ("the", "ザ"),
+    ("there", "ゼア"),
+    ("they", "ゼイ"),
+    ("think", "シンク"),
+    ("this", "ディス"),
+    ("three", "スリー"),
+    ("time", "タイム"),
+    ("to", "トゥー"),
+    ("twitter", "ツイッター"),
+    ("two", "トゥー"),
+    ("update", "アップデート"),
+    ("upload", "アップロード"),
+    ("user", "ユーザー"),
+    ("very", "ベリー"),
+    ("video", "ビデオ"),
+    ("voice", "ボイス"),
+    ("want", "ウォント"),
+    ("was", "ワズ"),
+    ("water", "ウォーター"),
+    ("we", "ウィー"),
+    ("web", "ウェブ"),
+    ("what", "ワット"),
+    ("when", "ウェン"),
+    ("where", "ウェア"),
+    ("which", "ウィッチ"),
+    ("who", "フー"),
+    ("why", "ホワイ"),
+    ("will", "ウィル"),
+    ("windows", "ウィンドウズ"),
+    ("with", "ウィズ"),
+    ("word", "ワード"),
+    ("world", "ワールド"),
+    ("yes", "イエス"),
+    ("you", "ユー"),
+    ("your", "ユア"),
+    ("youtube", "ユーチューブ"),
+];
+
+/// Spellings that are pronounced as other ones, where `Y` stands for "igh" pronounced as "ai"
+const SPELLINGS: [(&str, &str); 9] = [
+    ("tch", "ch"),
+    ("igh", "Y"),
+    ("gh", ""),
+    ("ph", "f"),
+    ("wh", "w"),
+    ("ck", "kk"),
+    ("qu", "kw"),
+    ("q", "k"),
+    ("x", "ks"),
+];
+
+/// Katakana of consonants followed by vowels in order of A, I, U, E and O rows
+const SYLLABLES: [(&str, [&str; 5]); 22] = [
+    ("", ["ア", "イ", "ウ", "エ", "オ"]),
+    ("b", ["バ", "ビ", "ブ", "ベ", "ボ"]),
+    ("ch", ["チャ", "チ", "チュ", "チェ", "チョ"]),
+    ("d", ["ダ", "ディ", "ドゥ", "デ", "ド"]),
+    ("f", ["ファ", "フィ", "フ", "フェ", "フォ"]),
+    ("g", ["ガ", "ギ", "グ", "ゲ", "ゴ"]),
+    ("h", ["ハ", "ヒ", "フ", "ヘ", "ホ"]),
+    ("j", ["ジャ", "ジ", "ジュ", "ジェ", "ジョ"]),
+    ("k", ["カ", "キ", "ク", "ケ", "コ"]),
+    ("l", ["ラ", "リ", "ル", "レ", "ロ"]),
+    ("m", ["マ", "ミ", "ム", "メ", "モ"]),
+    ("n", ["ナ", "ニ", "ヌ", "ネ", "ノ"]),
+    ("p", ["パ", "ピ", "プ", "ペ", "ポ"]),
+    ("r", ["ラ", "リ", "ル", "レ", "ロ"]),
+    ("s", ["サ", "シ", "ス", "セ", "ソ"]),
+    ("sh", ["シャ", "シ", "シュ", "シェ", "ショ"]),
+    ("t", ["タ", "ティ", "トゥ", "テ", "ト"]),
+    ("th", ["サ", "シ", "ス", "セ", "ソ"]),
+    ("v", ["ヴァ", "ヴィ", "ヴ", "ヴェ", "ヴォ"]),
+    ("w", ["ワ", "ウィ", "ウ", "ウェ", "ウォ"]),
+    ("y", ["ヤ", "イ", "ユ", "イェ", "ヨ"]),
+    ("z", ["ザ", "ジ", "ズ", "ゼ", "ゾ"]),
+];
+
+/// Katakana of consonants not followed by vowels
+const CODAS: [(&str, &str); 20] = [
+    ("b", "ブ"),
+    ("ch", "チ"),
+    ("d", "ド"),
+    ("f", "フ"),
+    ("g", "グ"),
+    ("h", ""),
+    ("j", "ジ"),
+    ("k", "ク"),
+    ("l", "ル"),
+    ("m", "ム"),
+    ("n", "ン"),
+    ("p", "プ"),
+    ("r", "ル"),
+    ("s", "ス"),
+    ("sh", "シュ"),
+    ("t", "ト"),
+    ("th", "ス"),
+    ("v", "ブ"),
+    ("w", "ウ"),
+    ("z", "ズ"),
+];
+
+/// Vowels spelled with two letters and their rows and following katakana
+const VOWEL_DIGRAPHS: [(&str, Row, &str); 17] = [
+    ("ai", Row::E, "イ"),
+    ("au", Row::O, "ー"),
+    ("aw", Row::O, "ー"),
+    ("ay", Row::E, "イ"),
+    ("ea", Row::I, "ー"),
+    ("ee", Row::I, "ー"),
+    ("ei", Row::E, "イ"),
+    ("ew", Row::Yu, "ー"),
+    ("ey", Row::E, "イ"),
+    ("ie", Row::I, "ー"),
+    ("oa", Row::O, "ー"),
+    ("oi", Row::O, "イ"),
+    ("oo", Row::U, "ー"),
+    ("ou", Row::A, "ウ"),
+    ("ow", Row::A, "ウ"),
+    ("oy", Row::O, "イ"),
+    ("ue", Row::Yu, "ー"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    A,
+    I,
+    U,
+    E,
+    O,
+    Yu,
+}
+
+/// Transliterates an English `word` into katakana without kanatrans, looking up the bundled loanwords first and
+/// approximating the pronunciation from the spelling otherwise. Words joined with hyphens are transliterated separately.
+pub(crate) fn transliterate(word: &str) -> Option<String> {
+    let katakana = word
+        .split(['-', ':'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let part = part.to_ascii_lowercase().replace('\'', "");
+            lookup(&part).or_else(|| approximate(&part))
+        })
+        .collect::<Option<Vec<_>>>()?
+        .concat();
+
+    (!katakana.is_empty()).then_some(katakana)
+}
+
+/// Whether lowercase `word` is one of the bundled loanwords.
+pub(crate) fn is_loanword(word: &str) -> bool {
+    LOANWORDS.binary_search_by_key(&word, |(word, _)| word).is_ok()
+}
+
+/// Looks up `word` in the loanwords, including its plural form.
+fn lookup(word: &str) -> Option<String> {
+    let find = |word: &str| {
+        LOANWORDS
+            .binary_search_by_key(&word, |(word, _)| word)
+            .ok()
+            .map(|index| LOANWORDS[index].1)
+    };
+
+    match find(word) {
+        Some(katakana) => Some(katakana.to_string()),
+        None => find(word.strip_suffix('s')?).map(|katakana| format!("{katakana}ズ")),
+    }
+}
+
+/// Approximates the pronunciation of `word` in katakana by splitting it into syllables by its spelling.
+fn approximate(word: &str) -> Option<String> {
+    let word = normalize(word);
+    let letters = word.as_bytes();
+
+    let mut katakana = String::new();
+    // Katakana following the last vowel, which is `None` if the last letter is not a vowel
+    let mut last_vowel = None;
+    let mut has_vowel = false;
+    let mut index = 0;
+    while index < letters.len() {
+        let onset = &word[index..index + onset_length(&letters[index..])];
+        let mut next = index + onset.len();
+        let is_doubled = onset.len() == 1 && letters.get(next) == Some(&letters[index]);
+        if is_doubled {
+            next += 1;
+        }
+
+        match vowel(letters, next, has_vowel) {
+            Some((row, suffix, length)) => {
+                if is_doubled && last_vowel == Some("") && matches!(onset, "k" | "p" | "t") {
+                    katakana.push('ッ');
+                }
+                katakana.push_str(&syllable(onset, row));
+                katakana.push_str(suffix);
+                last_vowel = Some(suffix);
+                has_vowel = true;
+                index = next + length;
+            },
+            // Silent "e" at the end
+            None if onset.is_empty() => index += 1,
+            None => {
+                let following = letters.get(next);
+                let coda = match onset {
+                    "n" if following == Some(&b'g') && vowel(letters, next + 1, true).is_none() => {
+                        next += 1;
+                        "ング"
+                    },
+                    "m" if matches!(following, Some(b'b' | b'p')) => "ン",
+                    "r" if last_vowel == Some("") => "ー",
+                    "r" if last_vowel.is_some() => "",
+                    _ => CODAS
+                        .iter()
+                        .find(|(consonant, _)| *consonant == onset)
+                        .map_or("", |(_, katakana)| katakana),
+                };
+                let is_end = next == letters.len() || &word[next..] == "s";
+                if is_end && last_vowel == Some("") && matches!(onset, "ch" | "d" | "g" | "k" | "p" | "sh" | "t") {
+                    katakana.push('ッ');
+                }
+                katakana.push_str(coda);
+                last_vowel = None;
+                index = next;
+            },
+        }
+    }
+
+    (!katakana.is_empty()).then_some(katakana)
+}
+
+/// Lowercases `word` and rewrites spellings into ones pronounced in the same way with fewer letters.
+fn normalize(word: &str) -> String {
+    let mut word = word.to_ascii_lowercase();
+    word.retain(|char| char.is_ascii_lowercase());
+    for (spelling, replacement) in SPELLINGS {
+        word = word.replace(spelling, replacement);
+    }
+    for (prefix, replacement) in [("kn", "n"), ("wr", "r")] {
+        if let Some(rest) = word.strip_prefix(prefix) {
+            word = format!("{replacement}{rest}");
+        }
+    }
+    if word.ends_with("mb") {
+        word.pop();
+    }
+
+    // "c" is pronounced as "s" before "e", "i" and "y", and as "k" otherwise except in "ch".
+    let letters = word.as_bytes();
+    letters
+        .iter()
+        .enumerate()
+        .map(|(index, letter)| match (letter, letters.get(index + 1)) {
+            (b'c', Some(b'h')) => 'c',
+            (b'c', Some(b'e' | b'i' | b'y')) => 's',
+            (b'c', _) => 'k',
+            (letter, _) => char::from(*letter),
+        })
+        .collect()
+}
+
+fn is_vowel(letter: u8) -> bool {
+    matches!(letter, b'a' | b'e' | b'i' | b'o' | b'u' | b'Y')
+}
+
+/// Length of consonants at the start of `letters` which are pronounced as one, or 0 if it starts with a vowel.
+fn onset_length(letters: &[u8]) -> usize {
+    match letters {
+        [b'c' | b's' | b't', b'h', ..] => 2,
+        [b'y', next, ..] if is_vowel(*next) => 1,
+        [letter, ..] if is_vowel(*letter) || *letter == b'y' => 0,
+        _ => 1,
+    }
+}
+
+/// Row of a vowel at `index` of `letters`, katakana following it and its length, or `None` if it is not a vowel or is
+/// silent.
+fn vowel(letters: &[u8], index: usize, has_vowel: bool) -> Option<(Row, &'static str, usize)> {
+    let rest = letters.get(index..)?;
+    let is_last = rest.len() == 1;
+    match *rest.first()? {
+        b'Y' => return Some((Row::A, "イ", 1)),
+        b'y' if rest.get(1).is_some_and(|letter| is_vowel(*letter)) => return None,
+        b'y' if is_last && has_vowel => return Some((Row::I, "ー", 1)),
+        b'y' if is_last => return Some((Row::A, "イ", 1)),
+        b'e' if is_last && has_vowel => return None,
+        letter if !is_vowel(letter) && letter != b'y' => return None,
+        _ => {},
+    }
+
+    // Vowels followed by "r" which is not followed by vowels are pronounced long
+    if let [first, b'r', following @ ..] = rest
+        && *first != b'y'
+        && !following
+            .first()
+            .is_some_and(|letter| is_vowel(*letter) || matches!(letter, b'r' | b'y'))
+    {
+        let row = match first {
+            b'o' => Row::O,
+            _ => Row::A,
+        };
+        return Some((row, "ー", 2));
+    }
+
+    if rest.starts_with(b"ie") && rest.len() == 2 {
+        return Some((Row::A, "イ", 2));
+    }
+    if let Some((_, row, suffix)) = VOWEL_DIGRAPHS
+        .iter()
+        .find(|(digraph, _, _)| rest.starts_with(digraph.as_bytes()))
+    {
+        return Some((*row, suffix, 2));
+    }
+
+    // Vowels followed by a consonant and silent "e" at the end are pronounced as their names
+    if let [first, consonant, b'e'] = rest
+        && !is_vowel(*consonant)
+        && !matches!(consonant, b'r' | b'w' | b'y')
+    {
+        let (row, suffix) = match first {
+            b'a' => (Row::E, "イ"),
+            b'e' => (Row::I, "ー"),
+            b'i' | b'y' => (Row::A, "イ"),
+            b'o' => (Row::O, "ー"),
+            _ => (Row::Yu, "ー"),
+        };
+        return Some((row, suffix, 1));
+    }
+
+    let row = match rest[0] {
+        b'e' => Row::E,
+        b'i' | b'y' => Row::I,
+        b'o' => Row::O,
+        _ => Row::A,
+    };
+    Some((row, "", 1))
+}
+
+fn syllable(onset: &str, row: Row) -> String {
+    let kana = SYLLABLES
+        .iter()
+        .find(|(consonant, _)| *consonant == onset)
+        .map_or(&SYLLABLES[0].1, |(_, kana)| kana);
+
+    match row {
+        Row::A => kana[0].to_string(),
+        Row::I => kana[1].to_string(),
+        Row::U => kana[2].to_string(),
+        Row::E => kana[3].to_string(),
+        Row::O => kana[4].to_string(),
+        Row::Yu => match onset {
+            "" | "y" => "ユ".to_string(),
+            "ch" | "j" | "sh" => kana[2].to_string(),
+            _ => kana[1].chars().take(1).chain(['ュ']).collect(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{transliterate, LOANWORDS};
+
+    #[test]
+    fn loanwords_are_sorted() {
+        assert!(LOANWORDS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn transliterate_loanword() {
+        for (word, expected) in [
+            ("hello", "ハロー"),
+            ("Discord", "ディスコード"),
+            ("games", "ゲームズ"),
+            ("check-in", "チェックイン"),
+        ] {
+            assert_eq!(transliterate(word).as_deref(), Some(expected), "word: {word}");
+        }
+    }
+
+    #[test]
+    fn approximate() {
+        for (word, expected) in [
+            ("test", "テスト"),
+            ("stop", "ストップ"),
+            ("back", "バック"),
+            ("check", "チェック"),
+            ("happy", "ハッピー"),
+            ("jump", "ジャンプ"),
+            ("club", "クラブ"),
+            ("number", "ナンバー"),
+            ("speed", "スピード"),
+            ("rain", "レイン"),
+            ("sing", "シング"),
+            ("fish", "フィッシュ"),
+            ("light", "ライト"),
+            ("phone", "フォーン"),
+            ("cute", "キュート"),
+            ("type", "タイプ"),
+            ("system", "システム"),
+            ("sky", "スカイ"),
+            ("success", "サクセス"),
+            ("don't", "ドント"),
+        ] {
+            assert_eq!(transliterate(word).as_deref(), Some(expected), "word: {word}");
+        }
+    }
+}
@@ -1,46 +1,41 @@
-use std::{borrow::Cow, error::Error, pin::Pin, sync::Arc, time::Duration};
+use std::{borrow::Cow, pin::Pin, str::FromStr, sync::Arc, time::Duration};
 
-use anyhow::{bail, Context as _, Result};
+use anyhow::{Context as _, Result};
 use database::PgPool;
-use futures::{
-    future::{self, join_all},
-    lock::Mutex,
-    stream,
-    StreamExt,
-    TryFutureExt,
-};
+use futures::{future::join_all, lock::Mutex, stream, StreamExt};
 use hashbrown::{HashMap, HashSet};
-use http_body_util::{BodyExt, Empty};
-use hyper::{
-    body::{Body, Buf, Bytes},
-    Request,
-    StatusCode,
-};
-use hyper_util::rt::TokioIo;
 use lazy_regex::Regex;
 use ordered_float::NotNan;
 use regex_lite::Captures;
-use serde::{de::DeserializeOwned, Deserialize};
 use serenity::{
-    all::{ChannelId as SerenityChannelId, ChannelType, GuildId, VoiceState},
+    all::{ChannelId as SerenityChannelId, ChannelType, GuildId, User, UserId, VoiceState},
     client::{Context, EventHandler},
     model::{application::Interaction, channel::Message, gateway::Ready},
 };
 use songbird::{input::Input, Call};
 use soundboard::sound::SoundId;
-use tokio::net::TcpStream;
 use tracing::instrument;
-use url::Url;
-use voicevox::dictionary::response::GetUserDictResult;
 
 use crate::{
+    attachment,
     audio::{cache::PredefinedUtterance, Audio, AudioRepository},
-    character_converter::to_half_width,
+    code,
     commands,
+    emoji,
+    ignore::IgnoreList,
+    link::{self, UrlMode},
+    markdown,
+    ng_word::{self, fetch_ng_words, NgWord},
+    number_converter,
+    permission,
+    reading,
+    rule::{fetch_rules, Rule},
+    slang::{self, SLANG_RULES},
     time_keeper::TimeKeeper,
+    transliterator::Transliterator,
     regex,
     speaker::Speaker,
-    utils::{get_manager, get_voicevox, normalize},
+    utils::{display_name, get_dictionary_words, get_manager, get_read_romaji, get_readings, get_user_voice, normalize},
 };
 
 #[derive(Debug)]
@@ -50,24 +45,50 @@ pub(crate) struct Handler<Repository> {
     pub(crate) audio_repository: Repository,
     pub(crate) connections: Arc<Mutex<HashMap<GuildId, SerenityChannelId>>>,
     pub(crate) time_keeper: Arc<Mutex<TimeKeeper<(GuildId, SoundId)>>>,
-    pub(crate) kanatrans_host: String,
-    pub(crate) kanatrans_port: u16,
+    /// Author whose message was read last in each guild, which is used to announce names
+    pub(crate) last_authors: Arc<Mutex<HashMap<GuildId, UserId>>>,
+    pub(crate) bitrate: u32,
+    pub(crate) transliterator: Arc<Transliterator>,
 }
 
-#[derive(Deserialize)]
-pub(crate) struct Arpabet {
-    word: String,
-    pronunciation: Vec<String>,
+enum Replacement<'a> {
+    General(&'static Regex, &'static str),
+    Code,
+    Markdown(bool),
+    Url(&'static Regex, UrlMode),
+    Emoji(&'a HashMap<u64, String>, bool),
+    Rule(&'a Rule),
+    Number,
+    Katakana(&'static Regex),
 }
 
-#[derive(Deserialize)]
-pub(crate) struct Katakana {
-    pronunciation: String,
+/// Settings and data of a guild that `replace_message` needs
+pub(crate) struct ReplaceOptions {
+    pub(crate) guild_id: GuildId,
+    pub(crate) settings: database::guild::Guild,
+    pub(crate) rules: Vec<Rule>,
+    /// Rules of built-in slang with settings of the guild, which are applied only if `settings.expand_slang` is true
+    pub(crate) slang_rules: Vec<Rule>,
+    pub(crate) dictionary_words: Vec<String>,
+    pub(crate) emoji_readings: HashMap<u64, String>,
+    pub(crate) ng_words: Vec<NgWord>,
+    /// Whether the author wants romaji to be read as Japanese
+    pub(crate) read_romaji: bool,
 }
 
-enum Replacement {
-    General(&'static Regex, &'static str),
-    Katakana(&'static Regex),
+impl ReplaceOptions {
+    pub(crate) async fn fetch(context: &Context, database: &PgPool, guild_id: GuildId, user_id: UserId) -> Result<Self> {
+        Ok(Self {
+            guild_id,
+            settings: database::guild::fetch_by_id(database, guild_id.get()).await?,
+            rules: fetch_rules(database, guild_id).await?,
+            slang_rules: slang::fetch_rules(database, guild_id).await?,
+            dictionary_words: get_dictionary_words(context).await?,
+            emoji_readings: emoji::fetch_readings(database, guild_id).await?,
+            ng_words: fetch_ng_words(database, guild_id).await?,
+            read_romaji: get_read_romaji(database, user_id).await?,
+        })
+    }
 }
 
 const SYSTEM_SPEAKER: &str = "1";
@@ -88,14 +109,75 @@ where
         Box::pin(async move {
             match interaction {
                 Interaction::Command(command) => {
+                    match permission::is_permitted(&self.database, &command).await {
+                        Ok(true) => {},
+                        Ok(false) => {
+                            if let Err(error) = permission::respond_forbidden(&context, &command).await {
+                                tracing::error!("failed to refuse /{}\nError: {error:?}", command.data.name);
+                            }
+                            return;
+                        },
+                        Err(error) => {
+                            tracing::error!("failed to check permission for /{}\nError: {error:?}", command.data.name);
+                            if let Err(error) = permission::respond_unchecked(&context, &command).await {
+                                tracing::error!("failed to respond to /{}\nError: {error:?}", command.data.name);
+                            }
+                            return;
+                        },
+                    }
+
                     let result = match command.data.name.as_str() {
-                        "dictionary" => commands::dictionary::run(&context, &self.audio_repository, &command).await,
+                        "config" => commands::config::run(&context, &command, &self.database, self.bitrate).await,
+                        "dictionary" => {
+                            commands::dictionary::run(&context, &self.audio_repository, &command, &self.database).await
+                        },
+                        "emoji" => commands::emoji::run(&context, &command, &self.database).await,
                         "help" => commands::help::run(&context, &command).await,
+                        "ignore" => commands::ignore::run(&context, &command, &self.database).await,
                         "join" => {
-                            commands::join::run(&context, &self.audio_repository, &mut *self.connections.lock().await, &command).await
+                            commands::join::run(
+                                &context,
+                                &self.audio_repository,
+                                &mut *self.connections.lock().await,
+                                &command,
+                                &self.database,
+                                self.bitrate,
+                            )
+                            .await
                         },
                         "leave" => commands::leave::run(&context, &command).await,
-                        "voice" => commands::voice::run(&context, &command, &self.database, &self.speaker).await,
+                        "ngword" => commands::ngword::run(&context, &command, &self.database).await,
+                        "nickname" => commands::nickname::run(&context, &command, &self.database).await,
+                        "replace" => {
+                            commands::replace::run(
+                                &context,
+                                &command,
+                                &self.database,
+                                &self.transliterator,
+                            )
+                            .await
+                        },
+                        "say" => {
+                            commands::say::run(
+                                &context,
+                                &command,
+                                &self.audio_repository,
+                                &self.database,
+                                &self.speaker,
+                                &self.transliterator,
+                            )
+                            .await
+                        },
+                        "voice" => {
+                            commands::voice::run(
+                                &context,
+                                &command,
+                                &self.database,
+                                &self.speaker,
+                                &self.transliterator,
+                            )
+                            .await
+                        },
                         "soundsticker" => commands::soundsticker::run(&context, &command, &self.database).await,
                         _ => Ok(()),
                     }
@@ -107,6 +189,7 @@ where
                 },
                 Interaction::Autocomplete(command) => {
                     let result = match command.data.name.as_str() {
+                        "say" => commands::say::autocomplete(&context, &command, &self.speaker).await,
                         "voice" => commands::voice::autocomplete(&context, &command, &self.speaker).await,
                         "soundsticker" => commands::soundsticker::autocomplete(&context, &command).await,
                         _ => Ok(()),
@@ -132,7 +215,7 @@ where
         's: 'async_trait,
     {
         Box::pin(async move {
-            if message.author.bot {
+            if message.author.id == context.cache.current_user().id {
                 return;
             }
 
@@ -167,6 +250,15 @@ where
                 return;
             }
 
+            match IgnoreList::fetch(&self.database, guild_id).await {
+                Ok(ignore_list) if ignore_list.ignores(&message) => return,
+                Ok(_) => {},
+                Err(error) => {
+                    tracing::error!("failed to fetch ignore list of guild {guild_id}\nError: {error:?}");
+                    return;
+                },
+            }
+
             let channel_bot_at = match channel_id_bot_at.to_channel(&context.http).await {
                 Ok(channel_bot_at) => channel_bot_at,
                 Err(error) => {
@@ -186,7 +278,9 @@ where
                     return;
                 },
             };
-            if !members
+            // Bots and webhooks that are not ignored are read even though they are not in the voice channel
+            let is_bot = message.author.bot || message.webhook_id.is_some();
+            if !is_bot && !members
                 .into_iter()
                 .map(|member| member.user)
                 .any(|user| message.author == user)
@@ -194,17 +288,19 @@ where
                 return;
             }
 
+            let mut soundsticker_ids = HashSet::new();
             if !message.sticker_items.is_empty() {
-                let sticker_ids = message.sticker_items.into_iter().map(|v| v.id.get());
+                let sticker_ids = message.sticker_items.iter().map(|v| v.id.get()).collect::<Vec<_>>();
                 let soundstickers = match database::soundsticker::fetch_by_ids(&self.database, sticker_ids.clone()).await {
                     Ok(soundstickers) => soundstickers,
                     Err(err) => {
-                        tracing::error!("failed to fetch soundstickers by ids: {:?}\nError: {err:?}", sticker_ids.collect::<Vec<_>>());
+                        tracing::error!("failed to fetch soundstickers by ids: {sticker_ids:?}\nError: {err:?}");
                         return;
                     },
                 };
 
                 for soundsticker in soundstickers {
+                    soundsticker_ids.insert(soundsticker.sticker_id);
                     let sound_id = SoundId::new(soundsticker.sound_id);
                     let sound_guild_id = soundsticker.sound_guild_id.map(GuildId::new).or(Some(guild_id));
 
@@ -224,67 +320,142 @@ where
                         },
                     };
                 }
-
-                return;
             }
 
-            let ids: Vec<i64> = vec![message.author.id.into()];
-            let speaker = match database::user::fetch_by_ids(&self.database, &ids).await {
-                Ok(users) => users
-                    .first()
-                    .unwrap_or(&database::user::User::default())
-                    .speaker_id
-                    .to_string(),
+            let (speaker, speed) = match get_user_voice(&self.database, message.author.id).await {
+                Ok(voice) => voice,
                 Err(error) => {
-                    tracing::error!("failed to fetch users by ids: {ids:?}\nError: {error:?}");
+                    tracing::error!("failed to get voice of user {}\nError: {error:?}", message.author.id);
                     return;
                 },
             };
 
-            let default = database::user::UserSpeaker::default();
-            let speed =
-                match database::user::fetch_with_speaker_by_ids(&self.database, &[message.author.id.into()]).await {
-                    Ok(speakers) => speakers
-                        .first()
-                        .unwrap_or(&default)
-                        .speed
-                        .or(default.speed)
-                        .unwrap_or(1.2),
+            let guild = match database::guild::fetch_by_id(&self.database, guild_id.get()).await {
+                Ok(guild) => guild,
+                Err(error) => {
+                    tracing::error!("failed to fetch guild {guild_id}\nError: {error:?}");
+                    return;
+                },
+            };
+            let volume = NotNan::new(guild.volume).or(NotNan::new(1.0)).unwrap();
+
+            {
+                // Stickers that have soundstickers are already played as sounds
+                let stickers = message
+                    .sticker_items
+                    .iter()
+                    .filter(|sticker| !soundsticker_ids.contains(&sticker.id.get()));
+                let mut content = reading::compose(&message, &guild, stickers);
+                for description in attachment::describe(&message.attachments, guild.read_filename) {
+                    content.push('\n');
+                    content.push_str(&description);
+                }
+
+                let readings = match get_readings(
+                    &self.database,
+                    guild_id,
+                    message.mentions.iter().map(|user| user.id).chain([message.author.id]),
+                )
+                .await
+                {
+                    Ok(readings) => readings,
                     Err(error) => {
-                        tracing::error!("failed to fetch speakers\nError: {error:?}");
+                        tracing::error!("failed to get readings of author and mentioned users\nError: {error:?}");
+                        HashMap::new()
+                    },
+                };
+
+                let dictionary_words = match get_dictionary_words(&context).await {
+                    Ok(dictionary_words) => dictionary_words,
+                    Err(error) => {
+                        tracing::error!("failed to get dictionary words\nError: {error:?}");
                         return;
                     },
                 };
 
-            {
-                let dictionary = {
-                    let voicevox = get_voicevox(&context)
-                        .await
-                        .context("failed to get voicevox client for /dictionary command")
-                        .unwrap();
-                    let voicevox = voicevox.lock().await;
-                    voicevox.dictionary.clone()
+                let rules = match fetch_rules(&self.database, guild_id).await {
+                    Ok(rules) => rules,
+                    Err(error) => {
+                        tracing::error!("failed to fetch replacement rules of guild {guild_id}\nError: {error:?}");
+                        Vec::new()
+                    },
+                };
+
+                let slang_rules = match slang::fetch_rules(&self.database, guild_id).await {
+                    Ok(slang_rules) => slang_rules,
+                    Err(error) => {
+                        tracing::error!("failed to fetch slang settings of guild {guild_id}\nError: {error:?}");
+                        SLANG_RULES.clone()
+                    },
+                };
+
+                let emoji_readings = match emoji::fetch_readings(&self.database, guild_id).await {
+                    Ok(emoji_readings) => emoji_readings,
+                    Err(error) => {
+                        tracing::error!("failed to fetch emoji readings of guild {guild_id}\nError: {error:?}");
+                        HashMap::new()
+                    },
                 };
-                let dictionary_words = dictionary
-                    .list()
-                    .await
-                    .map(|GetUserDictResult::Ok(list)| {
-                        list.values()
-                            .map(|item| to_half_width(&item.surface).into_owned())
-                            .collect::<Vec<_>>()
-                    })
-                    .unwrap_or_default();
-
-                for text in replace_message(
+
+                // Messages are not read rather than NG words are read when they cannot be fetched
+                let ng_words = match fetch_ng_words(&self.database, guild_id).await {
+                    Ok(ng_words) => ng_words,
+                    Err(error) => {
+                        tracing::error!("failed to fetch NG words of guild {guild_id}\nError: {error:?}");
+                        return;
+                    },
+                };
+
+                let read_romaji = match get_read_romaji(&self.database, message.author.id).await {
+                    Ok(read_romaji) => read_romaji,
+                    Err(error) => {
+                        tracing::error!("failed to get romaji setting of user {}\nError: {error:?}", message.author.id);
+                        database::user::User::default().read_romaji
+                    },
+                };
+
+                let options = ReplaceOptions {
+                    guild_id,
+                    settings: guild,
+                    rules,
+                    slang_rules,
+                    dictionary_words,
+                    emoji_readings,
+                    ng_words,
+                    read_romaji,
+                };
+
+                let replaced = replace_message(
                     &context,
-                    &message,
-                    &self.kanatrans_host,
-                    self.kanatrans_port,
-                    &dictionary_words,
+                    &options,
+                    &message.mentions,
+                    &readings,
+                    &content,
+                    &self.transliterator,
                 )
-                .await
-                .split('\n')
-                {
+                .await;
+                // The author is not regarded as the last one when nothing is read, e.g. the message is dropped by NG
+                // words
+                if replaced.split('\n').all(|text| text.trim().is_empty()) {
+                    return;
+                }
+
+                let last_author = self.last_authors.lock().await.insert(guild_id, message.author.id);
+                let replaced = match options.settings.announce_name && last_author != Some(message.author.id) {
+                    true => {
+                        let nick = message.member.as_ref().and_then(|member| member.nick.as_ref());
+                        let name = readings
+                            .get(&message.author.id)
+                            .map_or_else(|| display_name(nick, &message.author), String::as_str);
+                        let name = format!("{name}さん");
+                        let name =
+                            replace_message(&context, &options, &[], &readings, &name, &self.transliterator).await;
+                        Cow::Owned(format!("{name}\n{replaced}"))
+                    },
+                    false => replaced,
+                };
+
+                for text in replaced.split('\n') {
                     let text = text.trim();
                     if text.is_empty() {
                         continue;
@@ -294,22 +465,7 @@ where
                         text: text.to_string(),
                         speaker: speaker.clone(),
                         speed: NotNan::new(speed).or(NotNan::new(Speaker::default_speed())).unwrap(),
-                    };
-                    match self.audio_repository.get(audio).await {
-                        Ok(input) => {
-                            call.enqueue_input(input).await;
-                        },
-                        Err(error) => {
-                            tracing::error!("failed to get audio source\nError: {error:?}");
-                        },
-                    };
-                }
-
-                if !message.attachments.is_empty() {
-                    let audio = Audio {
-                        text: PredefinedUtterance::Attachment.as_ref().to_string(),
-                        speaker: speaker.clone(),
-                        speed: NotNan::new(speed).or(NotNan::new(Speaker::default_speed())).unwrap(),
+                        volume,
                     };
                     match self.audio_repository.get(audio).await {
                         Ok(input) => {
@@ -343,10 +499,17 @@ where
                     .set_commands(
                         &context.http,
                         vec![
+                            commands::config::register(),
                             commands::dictionary::register(),
+                            commands::emoji::register(),
                             commands::help::register(),
+                            commands::ignore::register(),
                             commands::join::register(),
                             commands::leave::register(),
+                            commands::ngword::register(),
+                            commands::nickname::register(),
+                            commands::replace::register(),
+                            commands::say::register(),
                             commands::voice::register(),
                             commands::soundsticker::register(),
                         ],
@@ -400,6 +563,7 @@ where
                 if is_disconnected {
                     let mut connections = self.connections.lock().await;
                     connections.remove(&guild_id);
+                    self.last_authors.lock().await.remove(&guild_id);
                 }
                 return;
             }
@@ -414,8 +578,23 @@ where
             let is_connected_bot_at = new_state.channel_id == channel_id_bot_at;
 
             if !is_disconnected && newly_connected && is_connected_bot_at {
+                let volume = match database::guild::fetch_by_id(&self.database, guild_id.get()).await {
+                    Ok(guild) => guild.volume,
+                    Err(error) => {
+                        tracing::error!("failed to fetch guild {guild_id}\nError: {error:?}");
+                        return;
+                    },
+                };
+                let readings = match get_readings(&self.database, guild_id, [new_state.user_id]).await {
+                    Ok(readings) => readings,
+                    Err(error) => {
+                        tracing::error!("failed to get reading of {}\nError: {error:?}", new_state.user_id);
+                        HashMap::new()
+                    },
+                };
+                let reading = readings.get(&new_state.user_id);
                 let mut connections = self.connections.lock().await;
-                handle_connect(&self.audio_repository, &new_state, &mut call, is_bot, &mut connections).await;
+                handle_connect(&self.audio_repository, &new_state, &mut call, is_bot, volume, reading, &mut connections).await;
                 return;
             }
 
@@ -456,29 +635,40 @@ where
     }
 }
 
-async fn replace_message<'a>(
+pub(crate) async fn replace_message<'a>(
     context: &Context,
-    message: &'a Message,
-    kanatrans_host: &str,
-    kanatrans_port: u16,
-    dictionary_words: &[String],
+    options: &ReplaceOptions,
+    mentions: &[User],
+    readings: &HashMap<UserId, String>,
+    content: &'a str,
+    transliterator: &Transliterator,
 ) -> Cow<'a, str> {
-    let Some(guild_id) = message.guild_id else {
-        return Cow::Borrowed(&message.content);
+    let url_mode = UrlMode::from_str(&options.settings.url_mode).unwrap_or_default();
+    let slang_rules = match options.settings.expand_slang {
+        true => options.slang_rules.as_slice(),
+        false => &[],
     };
-
     let replacements = [
-        Replacement::General(&regex::CODE, "\nコード省略\n"),
-        Replacement::General(&regex::URL, "\nURL\n"),
+        Replacement::Code,
+        Replacement::Markdown(options.settings.read_quote),
+        Replacement::Url(&regex::URL, url_mode),
         Replacement::General(&regex::WW, "$1ワラワラ$2"),
         Replacement::General(&regex::W, "$1ワラ$2"),
         Replacement::General(&regex::IDEOGRAPHIC_FULL_STOP, "。\n"),
-        Replacement::General(&regex::EMOJI, ":$1:"),
-        Replacement::Katakana(&regex::WORD),
-    ];
-
-    let text = normalize(context, &guild_id, &message.mentions, &message.content);
-    stream::iter(replacements.into_iter())
+        Replacement::Emoji(&options.emoji_readings, options.settings.collapse_emoji),
+    ]
+    .into_iter()
+    .chain(options.rules.iter().map(Replacement::Rule))
+    .chain(slang_rules.iter().map(Replacement::Rule))
+    .chain([Replacement::Number, Replacement::Katakana(&regex::WORD)]);
+    let dictionary_words = &options.dictionary_words;
+    let read_romaji = options.read_romaji;
+
+    let text = normalize(context, &options.guild_id, mentions, readings, content);
+    let Some(text) = ng_word::filter(text, &options.ng_words) else {
+        return Cow::Borrowed("");
+    };
+    let text = stream::iter(replacements)
         .fold(text, |accumulator, replacement| async move {
             match replacement {
                 Replacement::General(regex, replacer) => match regex.replace_all(&accumulator, replacer) {
@@ -486,58 +676,60 @@ async fn replace_message<'a>(
                     Cow::Borrowed(borrowed) => Cow::Owned(borrowed.to_owned()),
                     Cow::Owned(owned) => Cow::Owned(owned),
                 },
+                Replacement::Code => match code::replace(&accumulator) {
+                    Cow::Borrowed(borrowed) if borrowed.len() == accumulator.len() => accumulator,
+                    Cow::Borrowed(borrowed) => Cow::Owned(borrowed.to_owned()),
+                    Cow::Owned(owned) => Cow::Owned(owned),
+                },
+                Replacement::Markdown(read_quote) => match markdown::normalize(&accumulator, read_quote) {
+                    Cow::Borrowed(borrowed) if borrowed.len() == accumulator.len() => accumulator,
+                    Cow::Borrowed(borrowed) => Cow::Owned(borrowed.to_owned()),
+                    Cow::Owned(owned) => Cow::Owned(owned),
+                },
+                Replacement::Url(regex, mode) => {
+                    let replaced = regex.replace_all(&accumulator, |captures: &Captures| {
+                        format!("\n{}\n", link::describe(&captures[0], mode))
+                    });
+                    match replaced {
+                        Cow::Borrowed(borrowed) if borrowed.len() == accumulator.len() => accumulator,
+                        Cow::Borrowed(borrowed) => Cow::Owned(borrowed.to_owned()),
+                        Cow::Owned(owned) => Cow::Owned(owned),
+                    }
+                },
+                Replacement::Emoji(readings, collapse) => match emoji::replace(&accumulator, readings, collapse) {
+                    Cow::Borrowed(borrowed) if borrowed.len() == accumulator.len() => accumulator,
+                    Cow::Borrowed(borrowed) => Cow::Owned(borrowed.to_owned()),
+                    Cow::Owned(owned) => Cow::Owned(owned),
+                },
+                Replacement::Rule(rule) => match rule.apply(&accumulator) {
+                    Cow::Borrowed(borrowed) if borrowed.len() == accumulator.len() => accumulator,
+                    Cow::Borrowed(borrowed) => Cow::Owned(borrowed.to_owned()),
+                    Cow::Owned(owned) => Cow::Owned(owned),
+                },
+                Replacement::Number => number_converter::to_reading(accumulator),
                 Replacement::Katakana(regex) => {
                     let accumulator = &accumulator;
 
-                    let conversion_map = stream::iter(
-                        regex
-                            .find_iter(accumulator)
-                            .map(|word| word.as_str())
-                            .collect::<HashSet<_>>(),
-                    )
-                    .map(|word| async move {
-                        if word.chars().all(char::is_uppercase)
-                            || dictionary_words.iter().any(|dictionary_word| dictionary_word == word)
-                        {
-                            return None;
-                        }
-
-                        match get_arpabet(kanatrans_host, kanatrans_port, word)
-                            .and_then(async |arpabet| {
-                                get_katakana(
-                                    kanatrans_host,
-                                    kanatrans_port,
-                                    Some(&arpabet.word),
-                                    &arpabet.pronunciation,
-                                )
-                                .await
-                            })
-                            .await
-                        {
-                            Ok(katakana) => Some((word, katakana.pronunciation)),
-                            Err(err) => {
-                                tracing::error!("failed to get katakana\nError: {err:?}");
-                                None
-                            },
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .await;
-
-                    let conversion_map = future::join_all(conversion_map)
-                        .await
+                    let words = regex
+                        .find_iter(accumulator)
+                        .map(|word| word.as_str())
+                        .filter(|word| !dictionary_words.iter().any(|dictionary_word| dictionary_word == word))
+                        .collect::<HashSet<_>>()
                         .into_iter()
-                        .flatten()
-                        .collect::<HashMap<_, _>>();
+                        .collect::<Vec<_>>();
+                    if words.is_empty() {
+                        return accumulator.clone();
+                    }
 
-                    if conversion_map.is_empty() {
+                    let transliterations = transliterator.transliterate(&words, read_romaji).await;
+                    if transliterations.is_empty() {
                         return accumulator.clone();
                     }
 
                     let replaced = regex.replace_all(accumulator, |captures: &Captures| {
                         let word = &captures[0];
-                        match conversion_map.get(word) {
-                            Some(pronunciation) => pronunciation.to_owned(),
+                        match transliterations.get(word) {
+                            Some(katakana) => katakana.to_owned(),
                             None => word.to_owned(),
                         }
                     });
@@ -550,7 +742,10 @@ async fn replace_message<'a>(
                 },
             }
         })
-        .await
+        .await;
+
+    // Replacements can make up NG words, such as ones split by markdown, so they are filtered again
+    ng_word::filter(text, &options.ng_words).unwrap_or(Cow::Borrowed(""))
 }
 
 async fn handle_connect<Repository>(
@@ -558,6 +753,8 @@ async fn handle_connect<Repository>(
     state: &VoiceState,
     call: &mut Call,
     is_bot: bool,
+    volume: f32,
+    reading: Option<&String>,
     connections: &mut HashMap<GuildId, SerenityChannelId>,
 ) where
     Repository: AudioRepository<Input = Input> + Send + Sync,
@@ -572,8 +769,7 @@ async fn handle_connect<Repository>(
     let user_is = (!is_bot)
         .then(|| {
             let member = state.member.as_ref()?;
-            let user = &member.user;
-            let name = member.nick.as_ref().or(user.global_name.as_ref()).unwrap_or(&user.name);
+            let name = reading.map_or_else(|| display_name(member.nick.as_ref(), &member.user), String::as_str);
             Some(format!("{name}さんが"))
         })
         .flatten();
@@ -585,6 +781,7 @@ async fn handle_connect<Repository>(
                 text,
                 speaker: SYSTEM_SPEAKER.to_string(),
                 speed: NotNan::new(Speaker::default_speed()).unwrap(),
+                volume: NotNan::new(volume).or(NotNan::new(1.0)).unwrap(),
             };
             match audio_repository.get(audio).await {
                 Ok(input) => Some(input),
@@ -601,69 +798,3 @@ async fn handle_connect<Repository>(
         call.enqueue_input(input).await;
     }
 }
-
-async fn request<RequestBody, Response>(url: Url, request: Request<RequestBody>) -> Result<(StatusCode, Response)>
-where
-    RequestBody: Body + Send + Unpin + 'static,
-    RequestBody::Data: Send,
-    RequestBody::Error: Into<Box<dyn Error + Send + Sync>>,
-    Response: DeserializeOwned,
-{
-    let address = url.socket_addrs(|| None)?;
-    let stream = TcpStream::connect(&*address).await?;
-    let io = TokioIo::new(stream);
-    let (mut sender, connection) = hyper::client::conn::http1::handshake(io).await?;
-
-    tokio::task::spawn(async move {
-        if let Err(error) = connection.await {
-            tracing::error!("connection failed\nError: {error:?}");
-        }
-    });
-
-    let response = sender.send_request(request).await?;
-    let status = response.status();
-    let body = response.collect().await?.aggregate();
-    let json = serde_json::from_reader(body.reader())?;
-
-    Ok((status, json))
-}
-
-async fn get_arpabet(host: &str, port: u16, word: &str) -> Result<Arpabet> {
-    let url = Url::parse(&format!("http://{host}:{port}/arpabet/{word}"))?;
-    let req = Request::get(url.path())
-        .header(hyper::header::HOST, url.authority())
-        .body(Empty::<Bytes>::new())
-        .with_context(|| format!("failed to request with GET {url}"))?;
-    let (status, arpabet) = request(url, req).await?;
-
-    match status {
-        StatusCode::OK => Ok(arpabet),
-        StatusCode::UNPROCESSABLE_ENTITY => bail!("cannot convert {word} to ARPAbet"),
-        code => bail!("received unexpected {code} from GET /arpabet/{word}"),
-    }
-}
-
-async fn get_katakana(host: &str, port: u16, word: Option<&str>, pronunciation: &[String]) -> Result<Katakana> {
-    let pronunciation = pronunciation.join(" ");
-    let mut params = HashMap::new();
-    params.insert("pronunciation", pronunciation.as_str());
-    if let Some(word) = word {
-        params.insert("word", word);
-    }
-    let url = Url::parse_with_params(&format!("http://{host}:{port}/katakana"), params)?;
-    let uri = format!("{}?{}", url.path(), url.query().unwrap_or_default());
-    let req = Request::get(uri)
-        .header(hyper::header::HOST, url.authority())
-        .header(hyper::header::CONTENT_TYPE, "application/json")
-        .body(Empty::<Bytes>::new())
-        .with_context(|| format!("failed to request with GET {url}"))?;
-    let (status, katakana) = request(url.clone(), req).await?;
-
-    match status {
-        StatusCode::OK => Ok(katakana),
-        code => bail!(
-            "received unexpected {code} from GET /katakana with {}",
-            url.query().unwrap_or_default()
-        ),
-    }
-}
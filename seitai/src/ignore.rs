@@ -0,0 +1,98 @@
+use anyhow::Result;
+use database::PgPool;
+use hashbrown::HashSet;
+use serenity::all::{ChannelId, GuildId, Message, RoleId, UserId};
+
+pub(crate) const KIND_USER: &str = "user";
+pub(crate) const KIND_ROLE: &str = "role";
+pub(crate) const KIND_CHANNEL: &str = "channel";
+
+/// Users, roles and channels whose messages are not read in a guild, and bots or webhooks that are read exceptionally
+#[derive(Debug, Default)]
+pub(crate) struct IgnoreList {
+    users: HashSet<u64>,
+    roles: HashSet<u64>,
+    channels: HashSet<u64>,
+    readable_bots: HashSet<u64>,
+}
+
+impl IgnoreList {
+    pub(crate) async fn fetch(database: &PgPool, guild_id: GuildId) -> Result<Self> {
+        let mut list = Self {
+            readable_bots: database::readable_bot::fetch_by_guild_id(database, guild_id.get())
+                .await?
+                .into_iter()
+                .map(|bot| bot.bot_id as u64)
+                .collect(),
+            ..Default::default()
+        };
+
+        for ignore in database::ignore::fetch_by_guild_id(database, guild_id.get()).await? {
+            let ids = match ignore.kind.as_str() {
+                KIND_USER => &mut list.users,
+                KIND_ROLE => &mut list.roles,
+                KIND_CHANNEL => &mut list.channels,
+                kind => {
+                    tracing::warn!("unknown kind {kind} of ignored target {}", ignore.target_id);
+                    continue;
+                },
+            };
+            ids.insert(ignore.target_id as u64);
+        }
+
+        Ok(list)
+    }
+
+    /// Returns whether `message` should not be read. Messages by bots and webhooks are ignored unless they are made
+    /// readable.
+    pub(crate) fn ignores(&self, message: &Message) -> bool {
+        let is_bot = message.author.bot || message.webhook_id.is_some();
+        let roles = message.member.as_ref().map_or(&[][..], |member| member.roles.as_slice());
+
+        self.is_ignored(message.author.id, is_bot, roles, message.channel_id)
+    }
+
+    fn is_ignored(&self, author_id: UserId, is_bot: bool, roles: &[RoleId], channel_id: ChannelId) -> bool {
+        if is_bot && !self.readable_bots.contains(&author_id.get()) {
+            return true;
+        }
+
+        self.users.contains(&author_id.get())
+            || self.channels.contains(&channel_id.get())
+            || roles.iter().any(|role_id| self.roles.contains(&role_id.get()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hashbrown::HashSet;
+    use serenity::all::{ChannelId, RoleId, UserId};
+
+    use super::IgnoreList;
+
+    fn list() -> IgnoreList {
+        IgnoreList {
+            users: HashSet::from([1]),
+            roles: HashSet::from([2]),
+            channels: HashSet::from([3]),
+            readable_bots: HashSet::from([4]),
+        }
+    }
+
+    #[test]
+    fn ignores_targets() {
+        let list = list();
+        assert!(list.is_ignored(UserId::new(1), false, &[], ChannelId::new(10)));
+        assert!(list.is_ignored(UserId::new(10), false, &[RoleId::new(20), RoleId::new(2)], ChannelId::new(10)));
+        assert!(list.is_ignored(UserId::new(10), false, &[], ChannelId::new(3)));
+        assert!(!list.is_ignored(UserId::new(10), false, &[RoleId::new(20)], ChannelId::new(10)));
+    }
+
+    #[test]
+    fn ignores_bots_unless_readable() {
+        let list = list();
+        assert!(list.is_ignored(UserId::new(10), true, &[], ChannelId::new(10)));
+        assert!(!list.is_ignored(UserId::new(4), true, &[], ChannelId::new(10)));
+        assert!(list.is_ignored(UserId::new(4), true, &[], ChannelId::new(3)));
+    }
+}
@@ -0,0 +1,132 @@
+use strum::{AsRefStr, EnumString};
+use url::Url;
+
+use crate::audio::cache::PredefinedUtterance;
+
+/// How URLs in messages are read aloud, which is set per guild
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, AsRefStr)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum UrlMode {
+    /// Reads "URL"
+    #[default]
+    Url,
+    /// Reads a site name such as "ユーチューブのリンク"
+    Domain,
+    /// Reads nothing
+    Silent,
+}
+
+/// Readings of well-known sites, where subdomains of each domain are also matched
+const DOMAIN_READINGS: [(&str, &str); 24] = [
+    ("amazon.co.jp", "アマゾン"),
+    ("amazon.com", "アマゾン"),
+    ("bsky.app", "ブルースカイ"),
+    ("facebook.com", "フェイスブック"),
+    ("github.com", "ギットハブ"),
+    ("google.com", "グーグル"),
+    ("instagram.com", "インスタグラム"),
+    ("nicovideo.jp", "ニコニコ動画"),
+    ("nico.ms", "ニコニコ動画"),
+    ("note.com", "ノート"),
+    ("pixiv.net", "ピクシブ"),
+    ("qiita.com", "キータ"),
+    ("reddit.com", "レディット"),
+    ("spotify.com", "スポティファイ"),
+    ("steampowered.com", "スチーム"),
+    ("tiktok.com", "ティックトック"),
+    ("twitch.tv", "ツイッチ"),
+    ("twitter.com", "ツイッター"),
+    ("wikipedia.org", "ウィキペディア"),
+    ("x.com", "エックス"),
+    ("yahoo.co.jp", "ヤフー"),
+    ("youtu.be", "ユーチューブ"),
+    ("youtube.com", "ユーチューブ"),
+    ("zenn.dev", "ゼン"),
+];
+
+const DISCORD_DOMAINS: [&str; 3] = ["discord.com", "discordapp.com", "discord.gg"];
+
+/// Returns text to be read aloud instead of `url` in `mode`.
+pub(crate) fn describe(url: &str, mode: UrlMode) -> String {
+    if mode == UrlMode::Silent {
+        return String::new();
+    }
+
+    let Ok(url) = Url::parse(url) else {
+        return PredefinedUtterance::Url.as_ref().to_string();
+    };
+    let Some(host) = url.host_str() else {
+        return PredefinedUtterance::Url.as_ref().to_string();
+    };
+
+    if let Some(utterance) = discord(host, &url) {
+        return utterance.as_ref().to_string();
+    }
+
+    match mode {
+        UrlMode::Domain => {
+            let host = host.strip_prefix("www.").unwrap_or(host);
+            let name = DOMAIN_READINGS
+                .iter()
+                .find(|(domain, _)| matches_domain(host, domain))
+                .map_or(host, |(_, reading)| reading);
+            format!("{name}のリンク")
+        },
+        _ => PredefinedUtterance::Url.as_ref().to_string(),
+    }
+}
+
+fn discord(host: &str, url: &Url) -> Option<PredefinedUtterance> {
+    if !DISCORD_DOMAINS.iter().any(|domain| matches_domain(host, domain)) {
+        return None;
+    }
+
+    let mut segments = url.path_segments()?;
+    match (host, segments.next()) {
+        ("discord.gg", Some(code)) if !code.is_empty() => Some(PredefinedUtterance::InviteLink),
+        (_, Some("invite")) => Some(PredefinedUtterance::InviteLink),
+        (_, Some("channels")) if segments.count() == 3 => Some(PredefinedUtterance::MessageLink),
+        _ => None,
+    }
+}
+
+fn matches_domain(host: &str, domain: &str) -> bool {
+    host == domain || host.strip_suffix(domain).is_some_and(|subdomain| subdomain.ends_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe, UrlMode};
+
+    #[test]
+    fn describe_url() {
+        assert_eq!(describe("https://example.com/foo", UrlMode::Url), "URL");
+        assert_eq!(describe("https://www.youtube.com/watch?v=foo", UrlMode::Url), "URL");
+    }
+
+    #[test]
+    fn describe_domain() {
+        assert_eq!(describe("https://www.youtube.com/watch?v=foo", UrlMode::Domain), "ユーチューブのリンク");
+        assert_eq!(describe("https://youtu.be/foo", UrlMode::Domain), "ユーチューブのリンク");
+        assert_eq!(describe("https://ja.wikipedia.org/wiki/foo", UrlMode::Domain), "ウィキペディアのリンク");
+        assert_eq!(describe("https://example.com/foo", UrlMode::Domain), "example.comのリンク");
+        assert_eq!(describe("https://notx.com/", UrlMode::Domain), "notx.comのリンク");
+    }
+
+    #[test]
+    fn describe_silent() {
+        assert_eq!(describe("https://example.com/foo", UrlMode::Silent), "");
+        assert_eq!(describe("https://discord.gg/foo", UrlMode::Silent), "");
+    }
+
+    #[test]
+    fn describe_discord() {
+        for mode in [UrlMode::Url, UrlMode::Domain] {
+            assert_eq!(describe("https://discord.com/channels/1/2/3", mode), "メッセージリンク");
+            assert_eq!(describe("https://ptb.discord.com/channels/1/2/3", mode), "メッセージリンク");
+            assert_eq!(describe("https://discord.gg/foo", mode), "招待リンク");
+            assert_eq!(describe("https://discord.com/invite/foo", mode), "招待リンク");
+        }
+        assert_eq!(describe("https://discord.com/channels/1/2", UrlMode::Url), "URL");
+    }
+}
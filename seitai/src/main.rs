@@ -1,6 +1,6 @@
 use std::{env, process::exit, sync::Arc, time::Duration};
 
-use anyhow::{Context as _, Error, Result};
+use anyhow::{ensure, Context as _, Result};
 use cli::Application;
 use database::{ConnectOptions, PgConnectOptions, PgPool, PgPoolOptions};
 use futures::lock::Mutex;
@@ -11,21 +11,45 @@ use serenity::{client::Client, model::gateway::GatewayIntents, prelude::TypeMapK
 use songbird::SerenityInit;
 use tokio::signal::unix::{signal, SignalKind};
 use tracing::log::LevelFilter;
-use voicevox::Voicevox;
+use voicevox::{voicevox::SAMPLING_RATES, Voicevox};
 
 use crate::{
-    audio::{cache::{ConstCacheable, PredefinedUtterance}, processor::SongbirdAudioProcessor, VoicevoxAudioRepository},
+    audio::{
+        cache::{CachedUtterance, ConstCacheable},
+        processor::{SongbirdAudioProcessor, BITRATE_RANGE, DEFAULT_BITRATE},
+        VoicevoxAudioRepository,
+    },
+    dictionary_cache::{DictionaryCache, REFRESH_INTERVAL_SECS},
     speaker::Speaker,
+    transliterator::Transliterator,
 };
 
+mod attachment;
 mod audio;
 mod character_converter;
 mod cli;
+mod code;
 mod commands;
+mod dictionary_cache;
+mod embed;
+mod emoji;
+mod english;
 mod event_handler;
+mod ignore;
+mod link;
+mod markdown;
+mod ng_word;
+mod number_converter;
+mod pattern;
+mod permission;
+mod reading;
 mod regex;
+mod romaji;
+mod rule;
+mod slang;
 mod speaker;
 mod time_keeper;
+mod transliterator;
 mod utils;
 
 struct VoicevoxClient;
@@ -34,6 +58,12 @@ impl TypeMapKey for VoicevoxClient {
     type Value = Arc<Mutex<Voicevox>>;
 }
 
+struct DictionaryWords;
+
+impl TypeMapKey for DictionaryWords {
+    type Value = Arc<DictionaryCache>;
+}
+
 #[tokio::main]
 async fn main() {
     initialize_logging();
@@ -53,21 +83,18 @@ pub async fn start_bot() {
         },
     };
 
-    let kanatrans_host = match env::var("KANATRANS_HOST") {
-        Ok(token) => token,
-        Err(error) => {
-            tracing::error!("failed to fetch environment variable KANATRANS_HOST\nError: {error:?}");
-            exit(1);
-        },
-    };
-
-    let kanatrans_port = match env::var("KANATRANS_PORT")
-        .map_err(Error::from)
-        .and_then(|port| port.parse::<u16>().map_err(Error::from))
+    let bitrate = match env::var("AUDIO_BITRATE")
+        .ok()
+        .map(|bitrate| {
+            let bitrate = bitrate.parse::<u32>()?;
+            ensure!(BITRATE_RANGE.contains(&bitrate), "{bitrate} is out of {BITRATE_RANGE:?}");
+            Ok(bitrate)
+        })
+        .transpose()
     {
-        Ok(token) => token,
+        Ok(bitrate) => bitrate.unwrap_or(DEFAULT_BITRATE),
         Err(error) => {
-            tracing::error!("failed to fetch environment variable KANATRANS_PORT\nError: {error:?}");
+            tracing::error!("failed to parse environment variable AUDIO_BITRATE\nError: {error:?}");
             exit(1);
         },
     };
@@ -96,8 +123,20 @@ pub async fn start_bot() {
         },
     };
 
+    let kanatrans = match set_up_kanatrans() {
+        Ok(kanatrans) => kanatrans,
+        Err(error) => {
+            tracing::error!("failed to set up kanatrans client\nError: {error:?}");
+            exit(1);
+        },
+    };
+    if kanatrans.is_none() {
+        tracing::info!("KANATRANS_HOST is not set, so English words are transliterated offline");
+    }
+    let transliterator = Arc::new(Transliterator::new(kanatrans, pool.clone()));
+
     let audio_repository =
-        VoicevoxAudioRepository::new(voicevox.audio_generator.clone(), SongbirdAudioProcessor, ConstCacheable::<PredefinedUtterance>::new());
+        VoicevoxAudioRepository::new(voicevox.audio_generator.clone(), SongbirdAudioProcessor { bitrate }, ConstCacheable::<CachedUtterance>::new());
 
     let intents = GatewayIntents::non_privileged() | GatewayIntents::MESSAGE_CONTENT;
     let mut client = match Client::builder(token, intents)
@@ -107,8 +146,9 @@ pub async fn start_bot() {
             audio_repository,
             connections: Arc::new(Mutex::new(HashMap::new())),
             time_keeper: Arc::new(Mutex::new(TimeKeeper::new())),
-            kanatrans_host,
-            kanatrans_port,
+            last_authors: Arc::new(Mutex::new(HashMap::new())),
+            bitrate,
+            transliterator,
         })
         .register_songbird()
         .await
@@ -120,12 +160,25 @@ pub async fn start_bot() {
         },
     };
 
+    let dictionary_cache = Arc::new(DictionaryCache::new(voicevox.dictionary.clone()));
+
     {
         let mut data = client.data.write().await;
 
         data.insert::<VoicevoxClient>(Arc::new(Mutex::new(voicevox)));
+        data.insert::<DictionaryWords>(dictionary_cache.clone());
     }
 
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(REFRESH_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(error) = dictionary_cache.refresh().await {
+                tracing::error!("failed to refresh dictionary cache\nError: {error:?}");
+            }
+        }
+    });
+
     tokio::spawn(async move {
         if let Err(error) = client.start().await {
             tracing::error!("failed to start client\nError: {error:?}");
@@ -163,5 +216,33 @@ pub async fn set_up_database() -> Result<PgPool> {
 
 async fn set_up_voicevox() -> Result<Voicevox> {
     let voicevox_host = env::var("VOICEVOX_HOST").context("failed to fetch environment variable VOICEVOX_HOST")?;
-    Voicevox::build(&voicevox_host).context("failed to build voicevox client")
+    let mut voicevox = Voicevox::build(&voicevox_host).context("failed to build voicevox client")?;
+
+    if let Ok(sampling_rate) = env::var("AUDIO_SAMPLING_RATE") {
+        let sampling_rate = sampling_rate
+            .parse::<u32>()
+            .context("failed to parse environment variable AUDIO_SAMPLING_RATE")?;
+        ensure!(
+            SAMPLING_RATES.contains(&sampling_rate),
+            "AUDIO_SAMPLING_RATE {sampling_rate} is not one of {SAMPLING_RATES:?}"
+        );
+        voicevox.audio_generator.output_sampling_rate = sampling_rate;
+    }
+
+    Ok(voicevox)
+}
+
+/// Builds kanatrans client if `KANATRANS_HOST` is set, or returns `None` to transliterate English words offline.
+fn set_up_kanatrans() -> Result<Option<kanatrans::Client>> {
+    let Ok(kanatrans_host) = env::var("KANATRANS_HOST") else {
+        return Ok(None);
+    };
+    let kanatrans_port = env::var("KANATRANS_PORT")
+        .context("failed to fetch environment variable KANATRANS_PORT")?
+        .parse::<u16>()
+        .context("failed to parse environment variable KANATRANS_PORT")?;
+
+    kanatrans::Client::new(&kanatrans_host, kanatrans_port)
+        .map(Some)
+        .context("failed to build kanatrans client")
 }
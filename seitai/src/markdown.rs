@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use regex_lite::Regex;
+
+use crate::{audio::cache::PredefinedUtterance, regex};
+
+/// Normalizes Discord markdown in `text` into plain text to be read aloud. Spoilers are hidden, masked links are
+/// replaced with their text, and formatting such as headings, lists and emphasis is stripped. Quoted lines are skipped
+/// unless `read_quote` is true.
+pub(crate) fn normalize(text: &str, read_quote: bool) -> Cow<'_, str> {
+    let spoiler = format!("\n{}\n", PredefinedUtterance::Spoiler.as_ref());
+    let text = regex::SPOILER.replace_all(text, spoiler.as_str());
+    let text = replace(text, &regex::MASKED_LINK, "$1");
+
+    let mut lines = Vec::new();
+    let mut is_block_quote = false;
+    for line in text.split('\n') {
+        let (line, is_quote) = match line.strip_prefix(">>> ") {
+            Some(line) if !is_block_quote => {
+                is_block_quote = true;
+                (line, true)
+            },
+            _ => match line.strip_prefix("> ") {
+                Some(line) => (line, true),
+                None => (line, is_block_quote),
+            },
+        };
+        if is_quote && !read_quote {
+            continue;
+        }
+
+        let line = regex::HEADING.replace(line, "");
+        let line = replace(line, &regex::LIST_MARKER, "");
+        lines.push(line);
+    }
+
+    let normalized = lines.join("\n");
+    let normalized = replace(Cow::Owned(normalized), &regex::BOLD, "$1");
+    let normalized = replace(normalized, &regex::UNDERLINE, "$1");
+    let normalized = replace(normalized, &regex::STRIKETHROUGH, "$1");
+    let normalized = replace(normalized, &regex::ITALIC_ASTERISK, "$1");
+    let normalized = replace(normalized, &regex::ITALIC_UNDERSCORE, "$1$2$3").into_owned();
+
+    match normalized == text {
+        true => match text {
+            Cow::Borrowed(borrowed) => Cow::Borrowed(borrowed),
+            Cow::Owned(owned) => Cow::Owned(owned),
+        },
+        false => Cow::Owned(normalized),
+    }
+}
+
+fn replace<'a>(text: Cow<'a, str>, regex: &Regex, replacer: &str) -> Cow<'a, str> {
+    match text {
+        Cow::Borrowed(borrowed) => regex.replace_all(borrowed, replacer),
+        Cow::Owned(owned) => match regex.replace_all(&owned, replacer) {
+            Cow::Borrowed(_) => Cow::Owned(owned),
+            Cow::Owned(replaced) => Cow::Owned(replaced),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn normalize_spoiler() {
+        assert_eq!(normalize("犯人は||ヤス||だ", true), "犯人は\nネタバレ\nだ");
+        assert_eq!(normalize("||foo\nbar||", true), "\nネタバレ\n");
+        assert_eq!(normalize("a || b", true), "a || b");
+    }
+
+    #[test]
+    fn normalize_masked_link() {
+        assert_eq!(normalize("[ここ](https://example.com)を見て", true), "ここを見て");
+        assert_eq!(normalize("[ここ](<https://example.com/foo>)", true), "ここ");
+        assert_eq!(normalize("[foo] (bar)", true), "[foo] (bar)");
+    }
+
+    #[test]
+    fn normalize_formatting() {
+        assert_eq!(normalize("**太字**と*斜体*と__下線__と~~取り消し~~", true), "太字と斜体と下線と取り消し");
+        assert_eq!(normalize("***両方***", true), "両方");
+        assert_eq!(normalize("_italic_ snake_case_word", true), "italic snake_case_word");
+        assert_eq!(normalize("# 見出し\n-# 小さい文字", true), "見出し\n小さい文字");
+        assert_eq!(normalize("- foo\n* bar\n1. baz", true), "foo\nbar\nbaz");
+        assert_eq!(normalize("2 * 3 * 4", true), "2 * 3 * 4");
+    }
+
+    #[test]
+    fn normalize_quote() {
+        assert_eq!(normalize("> foo\nbar", true), "foo\nbar");
+        assert_eq!(normalize("> foo\nbar", false), "bar");
+        assert_eq!(normalize("foo\n>>> bar\nbaz", true), "foo\nbar\nbaz");
+        assert_eq!(normalize("foo\n>>> bar\nbaz", false), "foo");
+        assert_eq!(normalize(">foo", false), ">foo");
+    }
+
+    #[test]
+    fn normalize_plain_text() {
+        assert!(matches!(normalize("foo\nbar", false), std::borrow::Cow::Borrowed("foo\nbar")));
+    }
+}
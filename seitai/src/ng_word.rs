@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+
+use anyhow::Result;
+use database::PgPool;
+use regex_lite::{NoExpand, Regex};
+use serenity::all::GuildId;
+use strum::{AsRefStr, EnumString};
+
+use crate::{audio::cache::PredefinedUtterance, pattern};
+
+/// Maximum number of NG words in a guild
+pub(crate) const MAX_NG_WORDS: usize = 50;
+
+/// What to do with a message that contains an NG word
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, AsRefStr)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum Action {
+    /// Reads a beep instead of the word
+    #[default]
+    Beep,
+    /// Reads nothing of the message
+    Drop,
+}
+
+/// Word that a guild does not want to be read aloud, which is a regex or a literal matched case-insensitively
+#[derive(Debug, Clone)]
+pub(crate) struct NgWord {
+    regex: Regex,
+    action: Action,
+}
+
+impl NgWord {
+    /// Compiles and validates a word.
+    pub(crate) fn new(pattern: &str, is_regex: bool, action: Action) -> Result<Self> {
+        Ok(Self {
+            regex: pattern::compile(pattern, is_regex, true)?,
+            action,
+        })
+    }
+}
+
+/// Masks NG words in `text` with beeps, or returns `None` if `text` contains a word that drops the whole message.
+pub(crate) fn filter<'a>(text: Cow<'a, str>, ng_words: &[NgWord]) -> Option<Cow<'a, str>> {
+    if ng_words
+        .iter()
+        .any(|ng_word| ng_word.action == Action::Drop && ng_word.regex.is_match(&text))
+    {
+        return None;
+    }
+
+    let beep = format!("\n{}\n", PredefinedUtterance::Beep.as_ref());
+    let masked = ng_words
+        .iter()
+        .filter(|ng_word| ng_word.action == Action::Beep)
+        .fold(text, |text, ng_word| match ng_word.regex.is_match(&text) {
+            true => Cow::Owned(ng_word.regex.replace_all(&text, NoExpand(&beep)).into_owned()),
+            false => text,
+        });
+
+    Some(masked)
+}
+
+/// Fetches NG words of `guild_id`. Words that fail to compile are skipped.
+pub(crate) async fn fetch_ng_words(database: &PgPool, guild_id: GuildId) -> Result<Vec<NgWord>> {
+    let ng_words = database::ng_word::fetch_by_guild_id(database, guild_id.get()).await?;
+
+    Ok(pattern::compile_all(
+        ng_words,
+        "NG word",
+        |ng_word| ng_word.id,
+        |ng_word| NgWord::new(&ng_word.pattern, ng_word.is_regex, ng_word.action.parse().unwrap_or_default()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::markdown;
+    use super::{filter, Action, NgWord};
+
+    #[test]
+    fn mask_with_beep() {
+        let ng_words = [NgWord::new("foo", false, Action::Beep).unwrap()];
+        assert_eq!(filter(Cow::Borrowed("a FOO b"), &ng_words).unwrap(), "a \nピー\n b");
+        assert!(matches!(filter(Cow::Borrowed("a bar b"), &ng_words), Some(Cow::Borrowed("a bar b"))));
+    }
+
+    #[test]
+    fn mask_literal_and_regex() {
+        let ng_words = [
+            NgWord::new("a.b", false, Action::Beep).unwrap(),
+            NgWord::new(r"ba+d", true, Action::Beep).unwrap(),
+        ];
+        assert_eq!(filter(Cow::Borrowed("a.b axb baaad"), &ng_words).unwrap(), "\nピー\n axb \nピー\n");
+    }
+
+    #[test]
+    fn drop_message() {
+        let ng_words = [
+            NgWord::new("foo", false, Action::Beep).unwrap(),
+            NgWord::new("bar", false, Action::Drop).unwrap(),
+        ];
+        assert!(filter(Cow::Borrowed("foo Bar"), &ng_words).is_none());
+        assert!(filter(Cow::Borrowed("foo baz"), &ng_words).is_some());
+    }
+
+    #[test]
+    fn filter_words_split_by_markdown() {
+        let ng_words = [
+            NgWord::new("ばか", false, Action::Beep).unwrap(),
+            NgWord::new("foo", false, Action::Drop).unwrap(),
+        ];
+        assert_eq!(filter(Cow::Borrowed("ば**か**"), &ng_words).unwrap(), "ば**か**");
+        assert_eq!(filter(markdown::normalize("ば**か**", false), &ng_words).unwrap(), "\nピー\n");
+        assert!(filter(markdown::normalize("f__oo__", false), &ng_words).is_none());
+    }
+
+    #[test]
+    fn reject_invalid_words() {
+        assert!(NgWord::new("", false, Action::Beep).is_err());
+        assert!(NgWord::new("(", true, Action::Beep).is_err());
+        assert!(NgWord::new("a*", true, Action::Drop).is_err());
+    }
+}
@@ -0,0 +1,238 @@
+use std::borrow::Cow;
+
+use regex_lite::{Captures, Regex, Replacer};
+
+use crate::regex;
+
+/// Units of large numbers in Japanese, each of which is 10^4 times the previous
+const MYRIAD_UNITS: [&str; 5] = ["", "万", "億", "兆", "京"];
+
+/// Readings of units written after numbers
+const UNITS: [(&str, &str); 29] = [
+    ("km/h", "キロメートル毎時"),
+    ("km", "キロメートル"),
+    ("cm", "センチメートル"),
+    ("mm", "ミリメートル"),
+    ("m", "メートル"),
+    ("kg", "キログラム"),
+    ("mg", "ミリグラム"),
+    ("g", "グラム"),
+    ("mL", "ミリリットル"),
+    ("ml", "ミリリットル"),
+    ("L", "リットル"),
+    ("TB", "テラバイト"),
+    ("GB", "ギガバイト"),
+    ("MB", "メガバイト"),
+    ("KB", "キロバイト"),
+    ("kB", "キロバイト"),
+    ("GHz", "ギガヘルツ"),
+    ("MHz", "メガヘルツ"),
+    ("kHz", "キロヘルツ"),
+    ("Hz", "ヘルツ"),
+    ("fps", "エフピーエス"),
+    ("ms", "ミリ秒"),
+    ("px", "ピクセル"),
+    ("dB", "デシベル"),
+    ("mAh", "ミリアンペアアワー"),
+    ("kW", "キロワット"),
+    ("W", "ワット"),
+    ("°C", "度"),
+    ("℃", "度"),
+];
+
+/// Words just before a month and day without a year
+const DATE_PREFIXES: [&str; 4] = ["今日", "明日", "昨日", "本日"];
+/// Characters just after a month and day without a year, which start a day of the week or a day
+const DATE_SUFFIXES: [char; 4] = ['(', '（', '日', '曜'];
+
+/// Converts versions, dates, times, numbers grouped with commas, large numbers, percentages and numbers with units in
+/// `text` into readings in Japanese.
+pub(crate) fn to_reading<'a>(text: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
+    let text = text.into();
+    let text = replace(text, &regex::VERSION, |captures: &Captures| {
+        match (captures.get(1), captures.get(2).or(captures.get(3))) {
+            (Some(_), Some(version)) => format!("バージョン{}", version.as_str().replace('.', "点")),
+            (None, Some(version)) => version.as_str().replace('.', "点"),
+            _ => captures[0].to_string(),
+        }
+    });
+    let text = replace(text, &regex::DATE, |captures: &Captures| {
+        match (parse(&captures[2]), parse(&captures[3])) {
+            (month @ 1..=12, day @ 1..=31) => format!("{}年{month}月{day}日", parse(&captures[1])),
+            _ => captures[0].to_string(),
+        }
+    });
+    // Fractions such as 1/2 are also written with a slash, so it is read as a date only in the context of a date
+    let text = replace(text, &regex::MONTH_DAY, |captures: &Captures| {
+        let (prefix, suffix) = (&captures["prefix"], &captures["suffix"]);
+        let is_date = DATE_PREFIXES.contains(&prefix) || suffix.trim_start().starts_with(DATE_SUFFIXES);
+        match (is_date, parse(&captures["month"]), parse(&captures["day"])) {
+            (true, month @ 1..=12, day @ 1..=31) => {
+                let suffix = suffix.strip_prefix('日').unwrap_or(suffix);
+                format!("{prefix}{month}月{day}日{suffix}")
+            },
+            _ => captures[0].to_string(),
+        }
+    });
+    let text = replace(text, &regex::TIME, |captures: &Captures| {
+        let second = captures.get(3).map(|second| parse(second.as_str()));
+        match (parse(&captures[1]), parse(&captures[2]), second) {
+            (hour @ 0..=30, 0, None) => format!("{hour}時"),
+            (hour @ 0..=30, minute @ 0..=59, None) => format!("{hour}時{minute}分"),
+            (hour @ 0..=30, minute @ 0..=59, Some(second @ 0..=59)) => format!("{hour}時{minute}分{second}秒"),
+            _ => captures[0].to_string(),
+        }
+    });
+    let text = replace(text, &regex::GROUPED_NUMBER, |captures: &Captures| captures[0].replace(',', ""));
+    let text = replace(text, &regex::LARGE_NUMBER, |captures: &Captures| {
+        format!("{}{}", &captures[1], to_myriad(&captures[2]))
+    });
+    let text = replace(text, &regex::PERCENT, "${1}パーセント");
+    replace(text, &regex::UNIT, |captures: &Captures| {
+        let unit = UNITS
+            .iter()
+            .find(|(unit, _)| *unit == &captures[2])
+            .map_or(&captures[2], |(_, reading)| reading);
+        format!("{}{unit}{}", &captures[1], &captures[3])
+    })
+}
+
+fn replace<'a>(text: Cow<'a, str>, regex: &Regex, replacer: impl Replacer) -> Cow<'a, str> {
+    match text {
+        Cow::Borrowed(borrowed) => regex.replace_all(borrowed, replacer),
+        Cow::Owned(owned) => match regex.replace_all(&owned, replacer) {
+            Cow::Borrowed(_) => Cow::Owned(owned),
+            Cow::Owned(replaced) => Cow::Owned(replaced),
+        },
+    }
+}
+
+fn parse(digits: &str) -> u32 {
+    digits.parse().unwrap_or(u32::MAX)
+}
+
+/// Groups `digits` by 4 digits with units of large numbers such as "1億2345万".
+fn to_myriad(digits: &str) -> String {
+    let Ok(mut number) = digits.parse::<u128>() else {
+        return digits.to_string();
+    };
+
+    let mut groups = Vec::new();
+    for unit in MYRIAD_UNITS {
+        let group = number % 10000;
+        if group > 0 {
+            groups.push(format!("{group}{unit}"));
+        }
+        number /= 10000;
+    }
+    if number > 0 {
+        return digits.to_string();
+    }
+
+    groups.reverse();
+    groups.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_reading;
+
+    fn assert_readings(cases: &[(&str, &str)]) {
+        for (text, expected) in cases {
+            assert_eq!(to_reading(*text), *expected, "text: {text}");
+        }
+    }
+
+    #[test]
+    fn date() {
+        assert_readings(&[
+            ("2026/10/17", "2026年10月17日"),
+            ("2026-01-05に", "2026年1月5日に"),
+            ("10/17(土)", "10月17日(土)"),
+            ("10/17 （土）", "10月17日 （土）"),
+            ("明日1/5に", "明日1月5日に"),
+            ("1/5日", "1月5日"),
+            ("12/25曜日", "12月25日曜日"),
+            ("1/2/3", "1/2/3"),
+            ("13/1(月)", "13/1(月)"),
+            ("2026/13/01", "2026/13/01"),
+        ]);
+    }
+
+    #[test]
+    fn fraction() {
+        assert_readings(&[
+            ("1/2", "1/2"),
+            ("3/4拍子", "3/4拍子"),
+            ("約1/3に", "約1/3に"),
+            ("1/2 と 3/4", "1/2 と 3/4"),
+        ]);
+    }
+
+    #[test]
+    fn time() {
+        assert_readings(&[
+            ("12:30", "12時30分"),
+            ("9:05から", "9時5分から"),
+            ("21:00", "21時"),
+            ("25:30", "25時30分"),
+            ("12:30:45", "12時30分45秒"),
+            ("16:9", "16:9"),
+            ("12:60", "12:60"),
+        ]);
+    }
+
+    #[test]
+    fn number() {
+        assert_readings(&[
+            ("1,000", "1000"),
+            ("1,000,000円", "100万円"),
+            ("12345", "1万2345"),
+            ("100000000", "1億"),
+            ("1234567890123", "1兆2345億6789万123"),
+            ("3.14159", "3.14159"),
+            ("1234", "1234"),
+            ("012345", "012345"),
+            ("123456789012345678901", "123456789012345678901"),
+            ("1,00", "1,00"),
+        ]);
+    }
+
+    #[test]
+    fn percent() {
+        assert_readings(&[("50%", "50パーセント"), ("99.9％", "99.9パーセント"), ("%", "%")]);
+    }
+
+    #[test]
+    fn unit() {
+        assert_readings(&[
+            ("10km", "10キロメートル"),
+            ("1.5kg", "1.5キログラム"),
+            ("100km/h", "100キロメートル毎時"),
+            ("500mLのペットボトル", "500ミリリットルのペットボトル"),
+            ("16GB", "16ギガバイト"),
+            ("144Hz", "144ヘルツ"),
+            ("25℃", "25度"),
+            ("10m走", "10メートル走"),
+            ("10min", "10min"),
+            ("3ms", "3ミリ秒"),
+            ("abc10km", "abc10km"),
+        ]);
+    }
+
+    #[test]
+    fn version() {
+        assert_readings(&[
+            ("v1.2.3", "バージョン1点2点3"),
+            ("V2.0", "バージョン2点0"),
+            ("1.2.3", "1点2点3"),
+            ("192.168.0.1", "192点168点0点1"),
+            ("1.5", "1.5"),
+        ]);
+    }
+
+    #[test]
+    fn plain_text() {
+        assert!(matches!(to_reading("foo"), std::borrow::Cow::Borrowed("foo")));
+    }
+}
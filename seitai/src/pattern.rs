@@ -0,0 +1,70 @@
+use std::{borrow::Cow, fmt::Display};
+
+use anyhow::{ensure, Result};
+use regex_lite::{Regex, RegexBuilder};
+
+/// Maximum length of a pattern that a guild sets to replacement rules and NG words
+pub(crate) const MAX_PATTERN_LENGTH: u16 = 100;
+/// Limit of compiled size of a pattern to reject patterns that are too costly to run on every message
+const SIZE_LIMIT: usize = 1 << 16;
+
+/// Compiles and validates `pattern` that a guild sets, which is a regex or a literal.
+pub(crate) fn compile(pattern: &str, is_regex: bool, case_insensitive: bool) -> Result<Regex> {
+    ensure!(!pattern.is_empty(), "pattern is empty");
+
+    let source = match is_regex {
+        true => Cow::Borrowed(pattern),
+        false => Cow::Owned(regex_lite::escape(pattern)),
+    };
+    let regex = RegexBuilder::new(&source)
+        .case_insensitive(case_insensitive)
+        .size_limit(SIZE_LIMIT)
+        .build()?;
+    ensure!(!regex.is_match(""), "pattern matches empty string");
+
+    Ok(regex)
+}
+
+/// Compiles `rows` fetched from the database with `compile`. Rows that fail to compile are skipped and logged with
+/// `kind` and their `id`.
+pub(crate) fn compile_all<Row, Compiled, Id>(
+    rows: Vec<Row>,
+    kind: &str,
+    id: impl Fn(&Row) -> Id,
+    compile: impl Fn(&Row) -> Result<Compiled>,
+) -> Vec<Compiled>
+where
+    Id: Display,
+{
+    rows.into_iter()
+        .filter_map(|row| match compile(&row) {
+            Ok(compiled) => Some(compiled),
+            Err(error) => {
+                tracing::error!("failed to compile {kind} {}\nError: {error:?}", id(&row));
+                None
+            },
+        })
+        .collect()
+}
+
+/// Describes in Japanese whether a pattern is a regex, to be shown after the pattern.
+pub(crate) fn describe_kind(is_regex: bool) -> &'static str {
+    match is_regex {
+        true => "（正規表現）",
+        false => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compile;
+
+    #[test]
+    fn compile_literal_and_regex() {
+        assert!(compile("a.b", false, false).unwrap().is_match("a.b"));
+        assert!(!compile("a.b", false, false).unwrap().is_match("axb"));
+        assert!(compile("a.b", true, false).unwrap().is_match("axb"));
+        assert!(compile("foo", false, true).unwrap().is_match("FOO"));
+        assert!(!compile("foo", false, false).unwrap().is_match("FOO"));
+    }
+}
@@ -0,0 +1,187 @@
+use anyhow::Result;
+use database::{command_permission::CommandPermission, PgPool};
+use serenity::{
+    all::{CommandInteraction, Permissions, RoleId},
+    builder::{CreateEmbed, CreateInteractionResponseMessage},
+    client::Context,
+    model::Colour,
+};
+
+use crate::utils::respond;
+
+/// Commands whose subcommands changing data of a guild can be restricted by `/config permission`, with the
+/// subcommands and their names in Japanese
+pub(crate) const MANAGED_COMMANDS: [(&str, &[&str], &str); 2] = [
+    ("dictionary", &["add", "add-bulk", "update", "delete", "revert"], "辞書"),
+    ("soundsticker", &["link", "delete"], "サウンドステッカー"),
+];
+
+/// Discord permissions that can be required by `/config permission`, with their option values and names in Japanese
+pub(crate) const PERMISSIONS: [(Permissions, &str, &str); 3] = [
+    (Permissions::MANAGE_GUILD, "manage_guild", "サーバー管理"),
+    (Permissions::MANAGE_MESSAGES, "manage_messages", "メッセージの管理"),
+    (Permissions::MANAGE_ROLES, "manage_roles", "ロールの管理"),
+];
+
+/// Permission required to run managed commands in a guild that has not set one by `/config permission`, since the
+/// dictionary of VOICEVOX and readings of English words are shared across guilds
+pub(crate) const DEFAULT_PERMISSION: (Permissions, &str) = (Permissions::MANAGE_GUILD, "サーバー管理");
+
+/// Returns whether the user of `interaction` is allowed to run it in the guild. Commands that only show data are always
+/// allowed.
+pub(crate) async fn is_permitted(database: &PgPool, interaction: &CommandInteraction) -> Result<bool> {
+    let Some(guild_id) = interaction.guild_id else {
+        return Ok(true);
+    };
+    let subcommand = interaction.data.options.first().map(|option| option.name.as_str());
+    if !is_managing(&interaction.data.name, subcommand) {
+        return Ok(true);
+    }
+
+    let requirement = database::command_permission::fetch(database, guild_id.get(), &interaction.data.name).await?;
+    let (roles, permissions) = match &interaction.member {
+        Some(member) => (member.roles.as_slice(), member.permissions.unwrap_or_default()),
+        None => (&[][..], Permissions::empty()),
+    };
+
+    Ok(match requirement {
+        Some(requirement) => satisfies(&requirement, roles, permissions),
+        None => satisfies_default(permissions),
+    })
+}
+
+/// Tells the user of `interaction` that they are not allowed to run it.
+pub(crate) async fn respond_forbidden(context: &Context, interaction: &CommandInteraction) -> Result<()> {
+    let message = CreateInteractionResponseMessage::new()
+        .embed(
+            CreateEmbed::new()
+                .title("このコマンドを実行する権限がありません。")
+                .description("サーバーの管理者に `/config permission` の設定を確認してください。")
+                .colour(Colour::RED),
+        )
+        .ephemeral(true);
+    respond(context, interaction, &message).await
+}
+
+/// Tells the user of `interaction` that it cannot be run because their permission cannot be checked.
+pub(crate) async fn respond_unchecked(context: &Context, interaction: &CommandInteraction) -> Result<()> {
+    let message = CreateInteractionResponseMessage::new()
+        .embed(
+            CreateEmbed::new()
+                .title("権限の確認に失敗しました。")
+                .description("しばらくしてからもう一度お試しください。")
+                .colour(Colour::RED),
+        )
+        .ephemeral(true);
+    respond(context, interaction, &message).await
+}
+
+fn is_managing(command: &str, subcommand: Option<&str>) -> bool {
+    MANAGED_COMMANDS
+        .iter()
+        .find(|(name, _, _)| *name == command)
+        .is_some_and(|(_, subcommands, _)| subcommand.is_some_and(|subcommand| subcommands.contains(&subcommand)))
+}
+
+/// Administrators always satisfy `requirement`, and others need either the required role or permission.
+fn satisfies(requirement: &CommandPermission, roles: &[RoleId], permissions: Permissions) -> bool {
+    if permissions.administrator() {
+        return true;
+    }
+
+    let has_role = requirement
+        .role_id
+        .is_some_and(|role_id| roles.contains(&RoleId::new(role_id as u64)));
+    let has_permission = requirement
+        .permission
+        .map(|bits| Permissions::from_bits_truncate(bits as u64))
+        .is_some_and(|required| permissions.contains(required));
+
+    has_role || has_permission
+}
+
+/// Administrators and users with the default permission are allowed when a guild sets no requirement.
+fn satisfies_default(permissions: Permissions) -> bool {
+    permissions.administrator() || permissions.contains(DEFAULT_PERMISSION.0)
+}
+
+/// Describes `requirement` in Japanese to be shown by `/config`.
+pub(crate) fn describe(requirement: &CommandPermission) -> String {
+    let role = requirement.role_id.map(|role_id| format!("<@&{role_id}>"));
+    let permission = requirement.permission.map(|bits| {
+        let required = Permissions::from_bits_truncate(bits as u64);
+        PERMISSIONS
+            .iter()
+            .find(|(permission, _, _)| *permission == required)
+            .map_or_else(|| required.get_permission_names().join(", "), |(_, _, name)| name.to_string())
+    });
+
+    [role, permission].into_iter().flatten().collect::<Vec<_>>().join(" または ")
+}
+
+#[cfg(test)]
+mod tests {
+    use database::command_permission::CommandPermission;
+    use serenity::all::{Permissions, RoleId};
+
+    use super::{is_managing, satisfies, satisfies_default};
+
+    fn requirement(role_id: Option<i64>, permission: Option<Permissions>) -> CommandPermission {
+        CommandPermission {
+            guild_id: 1,
+            command: "dictionary".to_string(),
+            role_id,
+            permission: permission.map(|permission| permission.bits() as i64),
+        }
+    }
+
+    #[test]
+    fn managing_subcommands() {
+        assert!(is_managing("dictionary", Some("add")));
+        assert!(is_managing("dictionary", Some("add-bulk")));
+        assert!(is_managing("dictionary", Some("delete")));
+        assert!(is_managing("dictionary", Some("revert")));
+        assert!(is_managing("soundsticker", Some("link")));
+        assert!(!is_managing("dictionary", Some("list")));
+        assert!(!is_managing("dictionary", Some("history")));
+        assert!(!is_managing("soundsticker", Some("list")));
+        assert!(!is_managing("voice", Some("delete")));
+        assert!(!is_managing("dictionary", None));
+    }
+
+    #[test]
+    fn satisfies_role() {
+        let requirement = requirement(Some(10), None);
+        assert!(satisfies(&requirement, &[RoleId::new(20), RoleId::new(10)], Permissions::empty()));
+        assert!(!satisfies(&requirement, &[RoleId::new(20)], Permissions::MANAGE_GUILD));
+    }
+
+    #[test]
+    fn satisfies_permission() {
+        let requirement = requirement(None, Some(Permissions::MANAGE_MESSAGES));
+        assert!(satisfies(&requirement, &[], Permissions::MANAGE_MESSAGES | Permissions::SEND_MESSAGES));
+        assert!(!satisfies(&requirement, &[RoleId::new(10)], Permissions::SEND_MESSAGES));
+    }
+
+    #[test]
+    fn satisfies_either() {
+        let requirement = requirement(Some(10), Some(Permissions::MANAGE_GUILD));
+        assert!(satisfies(&requirement, &[RoleId::new(10)], Permissions::empty()));
+        assert!(satisfies(&requirement, &[], Permissions::MANAGE_GUILD));
+        assert!(!satisfies(&requirement, &[], Permissions::empty()));
+    }
+
+    #[test]
+    fn administrators_always_satisfy() {
+        let requirement = requirement(Some(10), None);
+        assert!(satisfies(&requirement, &[], Permissions::ADMINISTRATOR));
+        assert!(satisfies_default(Permissions::ADMINISTRATOR));
+    }
+
+    #[test]
+    fn requires_manage_guild_by_default() {
+        assert!(satisfies_default(Permissions::MANAGE_GUILD | Permissions::SEND_MESSAGES));
+        assert!(!satisfies_default(Permissions::MANAGE_MESSAGES | Permissions::SEND_MESSAGES));
+        assert!(!satisfies_default(Permissions::empty()));
+    }
+}
@@ -0,0 +1,170 @@
+use database::guild::Guild;
+use serenity::all::{Embed, Message, MessageReferenceKind, StickerItem};
+
+use crate::utils::display_name;
+
+/// Builds text to be read aloud for `message` including parts other than its content, such as reply, stickers that
+/// have no soundsticker, embeds, poll and forwarded messages, which are enabled in `settings`.
+pub(crate) fn compose<'a>(
+    message: &Message,
+    settings: &Guild,
+    stickers: impl IntoIterator<Item = &'a StickerItem>,
+) -> String {
+    let mut texts = Vec::new();
+
+    if settings.read_reply {
+        texts.extend(reply(message));
+    }
+    texts.push(message.content.clone());
+    if settings.read_sticker {
+        texts.extend(stickers.into_iter().map(sticker));
+    }
+    if settings.read_embed {
+        texts.extend(embeds(&message.embeds));
+    }
+    if settings.read_poll {
+        texts.extend(poll(message));
+    }
+    if settings.read_forward {
+        texts.extend(forwarded(message));
+    }
+
+    texts.retain(|text| !text.is_empty());
+    texts.join("\n")
+}
+
+/// "〇〇への返信" with a name of the author of the message replied to.
+fn reply(message: &Message) -> Option<String> {
+    let reference = message.message_reference.as_ref()?;
+    if reference.kind != MessageReferenceKind::Default {
+        return None;
+    }
+
+    let replied = message.referenced_message.as_ref()?;
+    let nick = replied.member.as_ref().and_then(|member| member.nick.as_ref());
+    let name = display_name(nick, &replied.author);
+    Some(format!("{name}への返信"))
+}
+
+fn sticker(sticker: &StickerItem) -> String {
+    format!("スタンプ {}", sticker.name)
+}
+
+/// Titles of embeds that are posted by bots or webhooks. Embeds generated from links are excluded because the link
+/// itself is read.
+fn embeds(embeds: &[Embed]) -> impl Iterator<Item = String> + '_ {
+    embeds
+        .iter()
+        .filter(|embed| embed.kind.as_deref().is_none_or(|kind| kind == "rich"))
+        .filter_map(|embed| embed.title.clone())
+}
+
+fn poll(message: &Message) -> Option<String> {
+    let question = message.poll.as_ref()?.question.text.as_ref()?;
+    Some(format!("投票 {question}"))
+}
+
+fn forwarded(message: &Message) -> impl Iterator<Item = String> + '_ {
+    let is_forwarded = message
+        .message_reference
+        .as_ref()
+        .is_some_and(|reference| reference.kind == MessageReferenceKind::Forward);
+
+    message
+        .message_snapshots
+        .iter()
+        .filter(move |_| is_forwarded)
+        .map(|snapshot| snapshot.content.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use database::guild::Guild;
+    use serenity::all::{ChannelId, Embed, Message, MessageReference, MessageReferenceKind, StickerItem};
+
+    use super::compose;
+
+    fn reply_to(name: &str) -> Message {
+        let mut replied = Message::default();
+        replied.author.name = name.to_string();
+
+        let mut message = Message::default();
+        message.content = "foo".to_string();
+        message.message_reference = Some(MessageReference::new(MessageReferenceKind::Default, ChannelId::new(1)));
+        message.referenced_message = Some(Box::new(replied));
+        message
+    }
+
+    fn embed(kind: &str, title: &str) -> Embed {
+        let mut embed = Embed::default();
+        embed.kind = Some(kind.to_string());
+        embed.title = Some(title.to_string());
+        embed
+    }
+
+    #[test]
+    fn compose_content_only() {
+        let mut message = Message::default();
+        message.content = "foo".to_string();
+
+        assert_eq!(compose(&message, &Guild::default(), []), "foo");
+    }
+
+    #[test]
+    fn compose_reply() {
+        let message = reply_to("bar");
+
+        assert_eq!(compose(&message, &Guild::default(), []), "barへの返信\nfoo");
+
+        let settings = Guild {
+            read_reply: false,
+            ..Default::default()
+        };
+        assert_eq!(compose(&message, &settings, []), "foo");
+    }
+
+    #[test]
+    fn compose_stickers() {
+        let message = Message::default();
+        let sticker: StickerItem =
+            serde_json::from_value(serde_json::json!({ "id": "1", "name": "ねこ", "format_type": 1 })).unwrap();
+
+        assert_eq!(compose(&message, &Guild::default(), [&sticker]), "スタンプ ねこ");
+
+        let settings = Guild {
+            read_sticker: false,
+            ..Default::default()
+        };
+        assert_eq!(compose(&message, &settings, [&sticker]), "");
+    }
+
+    #[test]
+    fn compose_rich_embeds_only() {
+        let mut message = Message::default();
+        message.embeds = vec![embed("rich", "お知らせ"), embed("link", "リンク先のタイトル")];
+
+        assert_eq!(compose(&message, &Guild::default(), []), "お知らせ");
+    }
+
+    #[test]
+    fn compose_forwarded_only_when_forwarded() {
+        let snapshot = serde_json::from_value(serde_json::json!({
+            "content": "forwarded",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "mentions": [],
+            "attachments": [],
+            "embeds": [],
+            "type": 0,
+            "flags": null,
+        }))
+        .unwrap();
+
+        let mut message = Message::default();
+        message.message_snapshots = vec![snapshot];
+        assert_eq!(compose(&message, &Guild::default(), []), "");
+
+        message.message_reference = Some(MessageReference::new(MessageReferenceKind::Forward, ChannelId::new(1)));
+        assert_eq!(compose(&message, &Guild::default(), []), "forwarded");
+    }
+}
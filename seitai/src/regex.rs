@@ -1,14 +1,33 @@
 use lazy_regex::{lazy_regex, Lazy, Regex};
 
-pub(crate) static CODE: Lazy<Regex> = lazy_regex!(r"(?:`[^`]+`|```[^`]+```)");
-pub(crate) static EMOJI: Lazy<Regex> = lazy_regex!(r"<(?:a)?:([[:word:]]+):\d+>");
+pub(crate) static BOLD: Lazy<Regex> = lazy_regex!(r"\*\*(.+?)\*\*");
+pub(crate) static DATE: Lazy<Regex> = lazy_regex!(r"\b(\d{4})[/-](\d{1,2})[/-](\d{1,2})\b");
+pub(crate) static EMOJI: Lazy<Regex> = lazy_regex!(r"<(?:a)?:([[:word:]]+):(\d+)>");
+pub(crate) static ENGLISH_WORD: Lazy<Regex> = lazy_regex!(r"^[[:alpha:]'-]{2,}$");
 pub(crate) static FULL_GRAPHICAL_AND_IDEOGRAPHIC_SPACE: Lazy<Regex> = lazy_regex!(r"[\u3000！-～]+");
+pub(crate) static GROUPED_NUMBER: Lazy<Regex> = lazy_regex!(r"\b\d{1,3}(?:,\d{3})+\b");
 pub(crate) static HALF_GRAPHICAL: Lazy<Regex> = lazy_regex!(r"[!-~]+");
+pub(crate) static HEADING: Lazy<Regex> = lazy_regex!(r"^(?:#{1,3}|-#) +");
 pub(crate) static HIRAGANA: Lazy<Regex> = lazy_regex!(r"[ぁ-ゖ]+");
 pub(crate) static IDEOGRAPHIC_FULL_STOP: Lazy<Regex> = lazy_regex!(r"。");
+pub(crate) static ITALIC_ASTERISK: Lazy<Regex> = lazy_regex!(r"\*([^*\s](?:[^*]*[^*\s])?)\*");
+pub(crate) static ITALIC_UNDERSCORE: Lazy<Regex> = lazy_regex!(r"(^|[^[:word:]])_([^_\s](?:[^_]*[^_\s])?)_([^[:word:]]|$)");
+pub(crate) static KANA: Lazy<Regex> = lazy_regex!(r"^[ぁ-ゖァ-ヺー]+$");
+pub(crate) static LARGE_NUMBER: Lazy<Regex> = lazy_regex!(r"(^|[^\d.])([1-9]\d{4,19})\b");
+pub(crate) static LIST_MARKER: Lazy<Regex> = lazy_regex!(r"^ *(?:[-*]|\d+\.) +");
+pub(crate) static MASKED_LINK: Lazy<Regex> = lazy_regex!(r"\[([^\[\]]+)\]\(<?[[:alpha:]][[:alnum:]+\-.]*?://[^\s()]+>?\)");
 pub(crate) static MENTION_CHANNEL: Lazy<Regex> = lazy_regex!(r"<[@#].+>");
+pub(crate) static MENTION_USER: Lazy<Regex> = lazy_regex!(r"<@!?(\d+)>");
+pub(crate) static MONTH_DAY: Lazy<Regex> = lazy_regex!(r"(?<prefix>^|今日|明日|昨日|本日|[^\d/])(?<month>\d{1,2})/(?<day>\d{1,2})(?<suffix>\s*[(（]|[^\d/]|$)");
+pub(crate) static PERCENT: Lazy<Regex> = lazy_regex!(r"(\d)[%％]");
 pub(crate) static SOUNDMOJI: Lazy<Regex> = lazy_regex!(r"<sound:(?<guild_id>\d+):(?<sound_id>\d+)>");
+pub(crate) static SPOILER: Lazy<Regex> = lazy_regex!(r"(?s)\|\|.+?\|\|");
+pub(crate) static STRIKETHROUGH: Lazy<Regex> = lazy_regex!(r"~~(.+?)~~");
+pub(crate) static TIME: Lazy<Regex> = lazy_regex!(r"\b(\d{1,2}):(\d{2})(?::(\d{2}))?\b");
+pub(crate) static UNDERLINE: Lazy<Regex> = lazy_regex!(r"__(.+?)__");
+pub(crate) static UNIT: Lazy<Regex> = lazy_regex!(r"\b(\d+(?:\.\d+)?)(km/h|km|cm|mm|m|kg|mg|g|mL|ml|L|TB|GB|MB|KB|kB|GHz|MHz|kHz|Hz|fps|ms|px|dB|mAh|kW|W|°C|℃)([^[:alpha:]/]|$)");
 pub(crate) static URL: Lazy<Regex> = lazy_regex!(r"[[:alpha:]][[:alnum:]+\-.]*?://[^\s]+");
+pub(crate) static VERSION: Lazy<Regex> = lazy_regex!(r"\b(?:([vV])(\d+(?:\.\d+)+)|(\d+\.\d+(?:\.\d+)+))\b");
 pub(crate) static W: Lazy<Regex> = lazy_regex!(r"([^ｗ[:word:]]|^)[wｗ]([^ｗ[:word:]]|$)");
 pub(crate) static WW: Lazy<Regex> = lazy_regex!(r"([^ｗ[:word:]]|^)[wｗ]{2,}([^ｗ[:word:]]|$)");
 // When pattern without `:?`s, only word of emoji between :s is extracted
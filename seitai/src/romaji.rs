@@ -0,0 +1,253 @@
+use crate::english;
+
+/// Hiragana of romaji syllables in Hepburn, Kunrei and input method styles, which are matched in order
+const SYLLABLES: [(&str, &str); 130] = [
+    ("kya", "きゃ"),
+    ("kyu", "きゅ"),
+    ("kyo", "きょ"),
+    ("gya", "ぎゃ"),
+    ("gyu", "ぎゅ"),
+    ("gyo", "ぎょ"),
+    ("sha", "しゃ"),
+    ("shi", "し"),
+    ("shu", "しゅ"),
+    ("she", "しぇ"),
+    ("sho", "しょ"),
+    ("sya", "しゃ"),
+    ("syu", "しゅ"),
+    ("syo", "しょ"),
+    ("jya", "じゃ"),
+    ("jyu", "じゅ"),
+    ("jyo", "じょ"),
+    ("zya", "じゃ"),
+    ("zyu", "じゅ"),
+    ("zyo", "じょ"),
+    ("cha", "ちゃ"),
+    ("chi", "ち"),
+    ("chu", "ちゅ"),
+    ("che", "ちぇ"),
+    ("cho", "ちょ"),
+    ("tya", "ちゃ"),
+    ("tyu", "ちゅ"),
+    ("tyo", "ちょ"),
+    ("tsu", "つ"),
+    ("nya", "にゃ"),
+    ("nyu", "にゅ"),
+    ("nyo", "にょ"),
+    ("hya", "ひゃ"),
+    ("hyu", "ひゅ"),
+    ("hyo", "ひょ"),
+    ("bya", "びゃ"),
+    ("byu", "びゅ"),
+    ("byo", "びょ"),
+    ("pya", "ぴゃ"),
+    ("pyu", "ぴゅ"),
+    ("pyo", "ぴょ"),
+    ("mya", "みゃ"),
+    ("myu", "みゅ"),
+    ("myo", "みょ"),
+    ("rya", "りゃ"),
+    ("ryu", "りゅ"),
+    ("ryo", "りょ"),
+    ("ka", "か"),
+    ("ki", "き"),
+    ("ku", "く"),
+    ("ke", "け"),
+    ("ko", "こ"),
+    ("ga", "が"),
+    ("gi", "ぎ"),
+    ("gu", "ぐ"),
+    ("ge", "げ"),
+    ("go", "ご"),
+    ("sa", "さ"),
+    ("si", "し"),
+    ("su", "す"),
+    ("se", "せ"),
+    ("so", "そ"),
+    ("za", "ざ"),
+    ("zi", "じ"),
+    ("zu", "ず"),
+    ("ze", "ぜ"),
+    ("zo", "ぞ"),
+    ("ja", "じゃ"),
+    ("ji", "じ"),
+    ("ju", "じゅ"),
+    ("je", "じぇ"),
+    ("jo", "じょ"),
+    ("ta", "た"),
+    ("ti", "ち"),
+    ("tu", "つ"),
+    ("te", "て"),
+    ("to", "と"),
+    ("da", "だ"),
+    ("di", "ぢ"),
+    ("du", "づ"),
+    ("de", "で"),
+    ("do", "ど"),
+    ("na", "な"),
+    ("ni", "に"),
+    ("nu", "ぬ"),
+    ("ne", "ね"),
+    ("no", "の"),
+    ("ha", "は"),
+    ("hi", "ひ"),
+    ("hu", "ふ"),
+    ("he", "へ"),
+    ("ho", "ほ"),
+    ("fa", "ふぁ"),
+    ("fi", "ふぃ"),
+    ("fu", "ふ"),
+    ("fe", "ふぇ"),
+    ("fo", "ふぉ"),
+    ("ba", "ば"),
+    ("bi", "び"),
+    ("bu", "ぶ"),
+    ("be", "べ"),
+    ("bo", "ぼ"),
+    ("pa", "ぱ"),
+    ("pi", "ぴ"),
+    ("pu", "ぷ"),
+    ("pe", "ぺ"),
+    ("po", "ぽ"),
+    ("ma", "ま"),
+    ("mi", "み"),
+    ("mu", "む"),
+    ("me", "め"),
+    ("mo", "も"),
+    ("ya", "や"),
+    ("yu", "ゆ"),
+    ("yo", "よ"),
+    ("ra", "ら"),
+    ("ri", "り"),
+    ("ru", "る"),
+    ("re", "れ"),
+    ("ro", "ろ"),
+    ("wa", "わ"),
+    ("wo", "を"),
+    ("va", "ゔぁ"),
+    ("vu", "ゔ"),
+    ("a", "あ"),
+    ("i", "い"),
+    ("u", "う"),
+    ("e", "え"),
+    ("o", "お"),
+    ("-", "ー"),
+];
+
+/// Common English words that are also spelled as valid romaji, in addition to the loanwords that are transliterated
+/// as English
+const ENGLISH_WORDS: [&str; 60] = [
+    "a", "age", "anyone", "area", "bake", "bike", "bone", "bore", "date", "die", "dome", "done", "dose", "fake", "fame",
+    "fine", "fire", "gate", "gone", "hate", "hero", "hide", "hike", "hire", "hope", "hose", "idea", "made", "mate",
+    "mine", "mute", "name", "none", "nose", "note", "pipe", "pose", "pure", "rate", "ride", "ripe", "rise", "rope",
+    "rose", "safe", "same", "side", "sure", "take", "tape", "tire", "todo", "tone", "too", "tube", "tune", "use",
+    "wake", "zero", "zone",
+];
+
+/// Converts `word` into hiragana if it is plausibly Japanese written in romaji, that is, it consists only of romaji
+/// syllables and is not a common English word.
+pub(crate) fn to_hiragana(word: &str) -> Option<String> {
+    let lowercase = word.to_ascii_lowercase();
+    if english::is_loanword(&lowercase) || ENGLISH_WORDS.contains(&lowercase.as_str()) {
+        return None;
+    }
+
+    parse(&lowercase)
+}
+
+/// Parses lowercase `word` as romaji into hiragana, or returns `None` if it contains anything but romaji.
+fn parse(word: &str) -> Option<String> {
+    let letters = word.as_bytes();
+    let mut hiragana = String::new();
+    let mut index = 0;
+    while index < letters.len() {
+        let rest = &word[index..];
+        match &letters[index..] {
+            // "n" not followed by vowels is a syllabic nasal, where "nn" and "n'" are also used to separate it from
+            // following vowels.
+            [b'n', b'n', b'a' | b'i' | b'u' | b'e' | b'o' | b'y', ..] => {
+                hiragana.push('ん');
+                index += 1;
+                continue;
+            },
+            [b'n', b'n', ..] | [b'n', b'\'', ..] => {
+                hiragana.push('ん');
+                index += 2;
+                continue;
+            },
+            [b'n'] | [b'n', b'b'..=b'd' | b'f'..=b'h' | b'j'..=b'm' | b'p'..=b't' | b'v'..=b'x' | b'z' | b'-', ..] => {
+                hiragana.push('ん');
+                index += 1;
+                continue;
+            },
+            // Doubled consonants and "tch" are geminated.
+            [first, second, ..] if first == second && !matches!(*first, b'a' | b'i' | b'u' | b'e' | b'o' | b'-') => {
+                hiragana.push('っ');
+                index += 1;
+                continue;
+            },
+            [b't', b'c', b'h', ..] => {
+                hiragana.push('っ');
+                index += 1;
+                continue;
+            },
+            _ => {},
+        }
+
+        let (romaji, kana) = SYLLABLES.iter().find(|(romaji, _)| rest.starts_with(romaji))?;
+        hiragana.push_str(kana);
+        index += romaji.len();
+    }
+
+    Some(hiragana)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, to_hiragana, ENGLISH_WORDS};
+    use crate::english;
+
+    #[test]
+    fn convert_romaji() {
+        for (word, expected) in [
+            ("arigatou", "ありがとう"),
+            ("nanikore", "なにこれ"),
+            ("kore", "これ"),
+            ("ha", "は"),
+            ("Sugoi", "すごい"),
+            ("konnichiha", "こんにちは"),
+            ("konnnichiwa", "こんにちわ"),
+            ("oyasumi", "おやすみ"),
+            ("otsukare", "おつかれ"),
+            ("yoroshiku", "よろしく"),
+            ("gakkou", "がっこう"),
+            ("matcha", "まっちゃ"),
+            ("kon'ya", "こんや"),
+            ("ra-men", "らーめん"),
+            ("shinkansen", "しんかんせん"),
+            ("kawaii", "かわいい"),
+            ("dame", "だめ"),
+            ("sore", "それ"),
+        ] {
+            assert_eq!(to_hiragana(word).as_deref(), Some(expected), "word: {word}");
+        }
+    }
+
+    #[test]
+    fn keep_english() {
+        for word in [
+            "name", "game", "time", "home", "are", "no", "so", "to", "go", "one", "use", "sure", "hello", "like",
+            "test", "ok", "voice", "discord", "news", "a", "die", "age", "idea", "area",
+        ] {
+            assert_eq!(to_hiragana(word), None, "word: {word}");
+        }
+    }
+
+    #[test]
+    fn english_words_are_romaji() {
+        for word in ENGLISH_WORDS {
+            assert!(parse(word).is_some(), "word: {word}");
+            assert!(!english::is_loanword(word), "word: {word}");
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+use anyhow::Result;
+use database::PgPool;
+use regex_lite::{NoExpand, Regex};
+use serenity::all::GuildId;
+
+use crate::pattern;
+
+/// Maximum number of replacement rules in a guild
+pub(crate) const MAX_RULES: usize = 25;
+pub(crate) const MAX_REPLACEMENT_LENGTH: u16 = 100;
+
+/// Replacement rule that a guild sets, which is a regex or a literal
+#[derive(Debug, Clone)]
+pub(crate) struct Rule {
+    regex: Regex,
+    replacement: String,
+    is_regex: bool,
+}
+
+impl Rule {
+    /// Compiles and validates a rule.
+    pub(crate) fn new(pattern: &str, replacement: &str, is_regex: bool) -> Result<Self> {
+        Ok(Self {
+            regex: pattern::compile(pattern, is_regex, false)?,
+            replacement: replacement.to_string(),
+            is_regex,
+        })
+    }
+
+    /// Returns the rule with the same pattern and another `replacement`.
+    pub(crate) fn with_replacement(&self, replacement: impl Into<String>) -> Self {
+        Self {
+            regex: self.regex.clone(),
+            replacement: replacement.into(),
+            is_regex: self.is_regex,
+        }
+    }
+
+    pub(crate) fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self.is_regex {
+            true => self.regex.replace_all(text, self.replacement.as_str()),
+            false => self.regex.replace_all(text, NoExpand(&self.replacement)),
+        }
+    }
+}
+
+/// Fetches replacement rules of `guild_id` in order of application. Rules that fail to compile are skipped.
+pub(crate) async fn fetch_rules(database: &PgPool, guild_id: GuildId) -> Result<Vec<Rule>> {
+    let rules = database::replacement_rule::fetch_by_guild_id(database, guild_id.get()).await?;
+
+    Ok(pattern::compile_all(
+        rules,
+        "replacement rule",
+        |rule| rule.id,
+        |rule| Rule::new(&rule.pattern, &rule.replacement, rule.is_regex),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rule;
+
+    #[test]
+    fn apply_literal() {
+        let rule = Rule::new("a.b", "$1", false).unwrap();
+        assert_eq!(rule.apply("a.b axb"), "$1 axb");
+    }
+
+    #[test]
+    fn apply_regex() {
+        let rule = Rule::new(r"(\d+)円", "${1}えん", true).unwrap();
+        assert_eq!(rule.apply("100円と200円"), "100えんと200えん");
+    }
+
+    #[test]
+    fn reject_invalid_rules() {
+        assert!(Rule::new("", "foo", false).is_err());
+        assert!(Rule::new("(", "foo", true).is_err());
+        assert!(Rule::new("a*", "foo", true).is_err());
+        assert!(Rule::new(r"\w{1000}{1000}", "foo", true).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+use anyhow::Result;
+use database::{slang_override::SlangOverride, PgPool};
+use lazy_regex::Lazy;
+use serenity::all::GuildId;
+
+use crate::rule::Rule;
+
+/// Kanji that are regarded as a part of a word, so that slang written in kanji is expanded only when standalone
+const KANJI: &str = "一-龯々〆";
+/// Kana that are regarded as a part of a word when following slang, since preceding ones are usually particles
+const KANA: &str = "ぁ-ゖァ-ヺー";
+
+/// Internet slang that is applied as replacement rules after the ones that a guild sets. Slang that is also valid
+/// romaji, such as "imo", is not included not to be expanded when romaji is read as Japanese.
+pub(crate) struct Slang {
+    /// Name to be shown and set by `/replace slang`
+    pub(crate) name: &'static str,
+    pattern: &'static str,
+    pub(crate) reading: &'static str,
+    /// Replacement where `{reading}` is replaced with the reading
+    replacement: &'static str,
+}
+
+impl Slang {
+    /// Returns the replacement with `reading`, where `$` in `reading` is escaped not to be a capture group.
+    fn replacement(&self, reading: &str) -> String {
+        self.replacement.replace("{reading}", &reading.replace('$', "$$"))
+    }
+}
+
+pub(crate) const SLANGS: [Slang; 15] = [
+    // Numbers followed by counters such as "888円" are not applause
+    slang("888", r"\b8{3,}([\s!！?？。、.…]|$)", "パチパチ", "{reading}${1}"),
+    slang("lol", r"\b(?i:lol)\b", "ワラ", "{reading}"),
+    slang("lmao", r"\b(?i:lmao)\b", "爆笑", "{reading}"),
+    slang("orz", r"\b(?:orz|OTL)\b", "がっくり", "{reading}"),
+    slang("kwsk", r"\b(?i:kwsk)\b", "くわしく", "{reading}"),
+    slang("ktkr", r"\b(?i:ktkr)\b", "キタコレ", "{reading}"),
+    slang("wktk", r"\b(?i:wktk)\b", "ワクテカ", "{reading}"),
+    slang("mjd", r"\b(?i:mjd)\b", "マジで", "{reading}"),
+    slang("mjk", r"\b(?i:mjk)\b", "マジか", "{reading}"),
+    slang("gg", r"\b(?i:gg)\b", "ジージー", "{reading}"),
+    slang("thx", r"\b(?i:thx|tks)\b", "サンクス", "{reading}"),
+    slang("brb", r"\b(?i:brb)\b", "すぐ戻る", "{reading}"),
+    slang("afk", r"\b(?i:afk)\b", "離席", "{reading}"),
+    slang("草", "(^|[^{kanji}])草+([^{kanji}{kana}]|$)", "くさ", "${1}{reading}${2}"),
+    slang("乙", "(^|[^{kanji}])乙([^{kanji}]|$)", "おつ", "${1}{reading}${2}"),
+];
+
+/// Rules of `SLANGS` with their default readings in the same order
+pub(crate) static SLANG_RULES: Lazy<Vec<Rule>> = Lazy::new(|| {
+    SLANGS
+        .iter()
+        .map(|slang| {
+            let pattern = slang.pattern.replace("{kanji}", KANJI).replace("{kana}", KANA);
+            Rule::new(&pattern, &slang.replacement(slang.reading), true).expect("slang pattern should be valid")
+        })
+        .collect()
+});
+
+const fn slang(
+    name: &'static str,
+    pattern: &'static str,
+    reading: &'static str,
+    replacement: &'static str,
+) -> Slang {
+    Slang {
+        name,
+        pattern,
+        reading,
+        replacement,
+    }
+}
+
+/// Returns rules of slang applied in a guild with `overrides`, where slang without a reading is skipped.
+pub(crate) fn rules(overrides: &[SlangOverride]) -> Vec<Rule> {
+    SLANGS
+        .iter()
+        .zip(SLANG_RULES.iter())
+        .filter_map(|(slang, rule)| {
+            let slang_override = overrides.iter().find(|slang_override| slang_override.slang == slang.name);
+            match slang_override.map(|slang_override| slang_override.reading.as_deref()) {
+                Some(Some(reading)) => Some(rule.with_replacement(slang.replacement(reading))),
+                Some(None) => None,
+                None => Some(rule.clone()),
+            }
+        })
+        .collect()
+}
+
+/// Fetches rules of slang applied in `guild_id`.
+pub(crate) async fn fetch_rules(database: &PgPool, guild_id: GuildId) -> Result<Vec<Rule>> {
+    let overrides = database::slang_override::fetch_by_guild_id(database, guild_id.get()).await?;
+    Ok(rules(&overrides))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use database::slang_override::SlangOverride;
+
+    use super::{rules, SLANGS, SLANG_RULES};
+    use crate::rule::Rule;
+
+    fn expand_with(rules: &[Rule], text: &str) -> String {
+        rules
+            .iter()
+            .fold(Cow::Borrowed(text), |text, rule| Cow::Owned(rule.apply(&text).into_owned()))
+            .into_owned()
+    }
+
+    fn expand(text: &str) -> String {
+        expand_with(&SLANG_RULES, text)
+    }
+
+    fn slang_override(slang: &str, reading: Option<&str>) -> SlangOverride {
+        SlangOverride {
+            guild_id: 1,
+            slang: slang.to_string(),
+            reading: reading.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn expand_slang() {
+        for (text, expected) in [
+            ("888", "パチパチ"),
+            ("88888888 すごい", "パチパチ すごい"),
+            ("LOL", "ワラ"),
+            ("それはlol", "それはワラ"),
+            ("orz", "がっくり"),
+            ("kwsk", "くわしく"),
+            ("ktkr!", "キタコレ!"),
+            ("gg", "ジージー"),
+            ("thx!", "サンクス!"),
+            ("草", "くさ"),
+            ("草草草", "くさ"),
+            ("それは草", "それはくさ"),
+            ("草。", "くさ。"),
+            ("今日も草 生える", "今日もくさ 生える"),
+            ("乙", "おつ"),
+            ("乙です", "おつです"),
+        ] {
+            assert_eq!(expand(text), expected, "text: {text}");
+        }
+    }
+
+    #[test]
+    fn keep_normal_words() {
+        for text in [
+            "18880円",
+            "888円",
+            "lollipop",
+            "orzo",
+            "egg",
+            "草原",
+            "薬草",
+            "草むしり",
+            "甲乙つけがたい",
+            "乙女",
+            "imo",
+        ] {
+            assert_eq!(expand(text), text, "text: {text}");
+        }
+    }
+
+    #[test]
+    fn override_slang() {
+        let overrides = [
+            slang_override("lol", None),
+            slang_override("草", Some("わら$1")),
+            slang_override("unknown", None),
+        ];
+        let rules = rules(&overrides);
+        assert_eq!(rules.len(), SLANGS.len() - 1);
+        assert_eq!(expand_with(&rules, "lol"), "lol");
+        assert_eq!(expand_with(&rules, "それは草。"), "それはわら$1。");
+        assert_eq!(expand_with(&rules, "888"), "パチパチ");
+    }
+
+    #[test]
+    fn unique_names() {
+        for (index, slang) in SLANGS.iter().enumerate() {
+            assert!(SLANGS[..index].iter().all(|other| other.name != slang.name), "name: {}", slang.name);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use database::PgPool;
+use futures::future::join_all;
+use hashbrown::HashMap;
+use kanatrans::{Client, KanatransError, MemoryCache};
+
+use crate::{english, romaji};
+
+/// Maximum number of words whose transliterations are cached in process
+const CACHE_CAPACITY: usize = 4096;
+
+/// Transliterator of English words into katakana with kanatrans, whose results are cached in process and in the
+/// database so that the same word is never requested twice. Words are transliterated offline if kanatrans is not
+/// configured or fails.
+#[derive(Debug)]
+pub(crate) struct Transliterator {
+    client: Option<Client>,
+    cache: MemoryCache,
+    database: PgPool,
+}
+
+impl Transliterator {
+    pub(crate) fn new(client: Option<Client>, database: PgPool) -> Self {
+        Self {
+            client,
+            cache: MemoryCache::new(CACHE_CAPACITY),
+            database,
+        }
+    }
+
+    /// Returns katakana of `words` keyed by them. Readings that users register are preferred to kanatrans, and words
+    /// that cannot be transliterated or consist only of uppercase letters are not contained unless registered. Words
+    /// that are plausibly romaji are converted into hiragana instead if `read_romaji` is true.
+    pub(crate) async fn transliterate<'a>(&self, words: &[&'a str], read_romaji: bool) -> HashMap<&'a str, String> {
+        let mut transliterations = self.fetch_english_readings(words).await;
+        let mut misses = Vec::new();
+        for &word in words {
+            if transliterations.contains_key(word) || word.chars().all(char::is_uppercase) {
+                continue;
+            }
+            if let Some(hiragana) = read_romaji.then(|| romaji::to_hiragana(word)).flatten() {
+                transliterations.insert(word, hiragana);
+                continue;
+            }
+            match self.cache.get(word) {
+                Some(Some(katakana)) => {
+                    transliterations.insert(word, katakana);
+                },
+                Some(None) => {},
+                None => misses.push(word),
+            }
+        }
+        if misses.is_empty() {
+            return transliterations;
+        }
+
+        match database::transliteration::fetch_by_words(&self.database, &misses).await {
+            Ok(stored) => {
+                for transliteration in stored {
+                    self.cache
+                        .insert(&transliteration.word, Some(transliteration.katakana.clone()));
+                    if let Some(index) = misses.iter().position(|word| *word == transliteration.word) {
+                        transliterations.insert(misses.swap_remove(index), transliteration.katakana);
+                    }
+                }
+            },
+            Err(error) => {
+                tracing::error!("failed to fetch transliterations\nError: {error:?}");
+            },
+        }
+
+        let Some(client) = &self.client else {
+            for word in misses {
+                let katakana = english::transliterate(word);
+                self.cache.insert(word, katakana.clone());
+                transliterations.extend(katakana.map(|katakana| (word, katakana)));
+            }
+            return transliterations;
+        };
+
+        let results = join_all(
+            misses
+                .into_iter()
+                .map(async |word| (word, client.transliterate(word).await)),
+        )
+        .await;
+        let mut failure = None;
+        for (word, result) in results {
+            match result {
+                Ok(katakana) => {
+                    if let Err(error) = database::transliteration::create(&self.database, word, &katakana).await {
+                        tracing::error!("failed to store transliteration of {word}\nError: {error:?}");
+                    }
+                    self.cache.insert(word, Some(katakana.clone()));
+                    transliterations.insert(word, katakana);
+                },
+                Err(KanatransError::Unconvertible(_)) => {
+                    let katakana = english::transliterate(word);
+                    self.cache.insert(word, katakana.clone());
+                    transliterations.extend(katakana.map(|katakana| (word, katakana)));
+                },
+                // Results of the fallback are not cached so that kanatrans is requested again once it recovers.
+                Err(error) => {
+                    transliterations.extend(english::transliterate(word).map(|katakana| (word, katakana)));
+                    failure.get_or_insert(error);
+                },
+            }
+        }
+        if let Some(error) = failure {
+            tracing::error!(
+                "failed to get katakana from kanatrans, so words are transliterated offline\nError: {error:?}"
+            );
+        }
+
+        transliterations
+    }
+
+    /// Returns readings of `words` that users register, which are looked up case-insensitively.
+    async fn fetch_english_readings<'a>(&self, words: &[&'a str]) -> HashMap<&'a str, String> {
+        let lowercases = words.iter().map(|word| word.to_lowercase()).collect::<Vec<_>>();
+        let lowercases = lowercases.iter().map(String::as_str).collect::<Vec<_>>();
+        let readings = match database::english_reading::fetch_by_words(&self.database, &lowercases).await {
+            Ok(readings) => readings,
+            Err(error) => {
+                tracing::error!("failed to fetch english readings\nError: {error:?}");
+                return HashMap::new();
+            },
+        };
+
+        words
+            .iter()
+            .zip(lowercases)
+            .filter_map(|(&word, lowercase)| {
+                let reading = readings.iter().find(|reading| reading.word == lowercase)?;
+                Some((word, reading.reading.clone()))
+            })
+            .collect()
+    }
+}
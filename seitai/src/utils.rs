@@ -1,19 +1,28 @@
 use std::{borrow::Cow, sync::Arc};
 
 use anyhow::{Context as _, Result};
+use database::PgPool;
 use futures::lock::Mutex;
+use hashbrown::HashMap;
+use regex_lite::Captures;
 use serenity::{
-    all::{GuildId, User},
-    builder::{CreateInteractionResponse, CreateInteractionResponseMessage},
+    all::{GuildId, User, UserId},
+    builder::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage},
     client::Context,
-    model::{application::CommandInteraction, guild::Guild},
+    model::{application::CommandInteraction, guild::Guild, Colour},
     utils::{content_safe, ContentSafeOptions},
 };
 use songbird::Songbird;
 use soundboard::sound::SoundId;
 use voicevox::Voicevox;
 
-use crate::{regex::{self, SOUNDMOJI}, VoicevoxClient};
+use crate::{
+    dictionary_cache::DictionaryCache,
+    regex::{self, SOUNDMOJI},
+    speaker::Speaker,
+    DictionaryWords,
+    VoicevoxClient,
+};
 
 pub(crate) async fn get_manager(context: &Context) -> Result<Arc<Songbird>> {
     songbird::get(context)
@@ -54,9 +63,39 @@ pub(crate) async fn respond(
     Ok(())
 }
 
-pub(crate) fn normalize<'a>(context: &Context, guild_id: &GuildId, users: &[User], text: &'a str) -> Cow<'a, str> {
+/// Responds with `description` as an error.
+pub(crate) async fn respond_error(
+    context: &Context,
+    interaction: &CommandInteraction,
+    description: &str,
+    ephemeral: bool,
+) -> Result<()> {
+    let message = CreateInteractionResponseMessage::new()
+        .embed(CreateEmbed::new().description(description).colour(Colour::RED))
+        .ephemeral(ephemeral);
+    respond(context, interaction, &message).await
+}
+
+/// Cleans mentions in `text` into names. Users who have `readings` are mentioned by them instead of their names.
+pub(crate) fn normalize<'a>(
+    context: &Context,
+    guild_id: &GuildId,
+    users: &[User],
+    readings: &HashMap<UserId, String>,
+    text: &'a str,
+) -> Cow<'a, str> {
     match regex::MENTION_CHANNEL.is_match(text) {
         true => {
+            let text = regex::MENTION_USER.replace_all(text, |captures: &Captures| {
+                captures[1]
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|id| *id != 0)
+                    .and_then(|id| readings.get(&UserId::new(id)))
+                    .map(|reading| format!("@{reading}"))
+                    .unwrap_or_else(|| captures[0].to_string())
+            });
+
             let content_safe_options = ContentSafeOptions::new()
                 .clean_role(true)
                 .clean_user(true)
@@ -65,14 +104,94 @@ pub(crate) fn normalize<'a>(context: &Context, guild_id: &GuildId, users: &[User
                 .clean_here(false)
                 .clean_everyone(false);
 
-            let normalized = content_safe(&context.cache, text, &content_safe_options, users);
+            let normalized = content_safe(&context.cache, &text, &content_safe_options, users);
             Cow::Owned(normalized)
         },
         false => Cow::Borrowed(text),
     }
 }
 
+/// Resolves a name of `user` shown in a guild in order of `nick`, global name and username.
+pub(crate) fn display_name<'a>(nick: Option<&'a String>, user: &'a User) -> &'a str {
+    nick.or(user.global_name.as_ref()).unwrap_or(&user.name)
+}
+
 pub(crate) async fn get_voicevox(context: &Context) -> Option<Arc<Mutex<Voicevox>>> {
     let data = context.data.read().await;
     data.get::<VoicevoxClient>().cloned()
 }
+
+pub(crate) async fn get_dictionary_cache(context: &Context) -> Option<Arc<DictionaryCache>> {
+    let data = context.data.read().await;
+    data.get::<DictionaryWords>().cloned()
+}
+
+/// Returns words registered in the dictionary of VOICEVOX, which must not be transliterated by kanatrans.
+pub(crate) async fn get_dictionary_words(context: &Context) -> Result<Vec<String>> {
+    let cache = get_dictionary_cache(context)
+        .await
+        .context("failed to get dictionary cache")?;
+
+    let words = match cache.words().await {
+        Ok(words) => words,
+        Err(error) => {
+            tracing::error!("failed to fetch dictionary words\nError: {error:?}");
+            Vec::new()
+        },
+    };
+
+    Ok(words)
+}
+
+/// Returns speaker id and speed that `user_id` uses to read aloud.
+pub(crate) async fn get_user_voice(database: &PgPool, user_id: UserId) -> Result<(String, f32)> {
+    let ids: Vec<i64> = vec![user_id.into()];
+    let speaker = database::user::fetch_by_ids(database, &ids)
+        .await
+        .with_context(|| format!("failed to fetch users by ids: {ids:?}"))?
+        .first()
+        .unwrap_or(&database::user::User::default())
+        .speaker_id
+        .to_string();
+
+    let default = database::user::UserSpeaker::default();
+    let speed = database::user::fetch_with_speaker_by_ids(database, &ids)
+        .await
+        .context("failed to fetch speakers")?
+        .first()
+        .unwrap_or(&default)
+        .speed
+        .or(default.speed)
+        .unwrap_or(Speaker::default_speed());
+
+    Ok((speaker, speed))
+}
+
+/// Returns whether `user_id` wants romaji in messages to be read as Japanese.
+pub(crate) async fn get_read_romaji(database: &PgPool, user_id: UserId) -> Result<bool> {
+    let ids: Vec<i64> = vec![user_id.into()];
+    let read_romaji = database::user::fetch_by_ids(database, &ids)
+        .await
+        .with_context(|| format!("failed to fetch users by ids: {ids:?}"))?
+        .first()
+        .map_or(database::user::User::default().read_romaji, |user| user.read_romaji);
+
+    Ok(read_romaji)
+}
+
+/// Returns readings of names that `user_ids` set in `guild_id`.
+pub(crate) async fn get_readings(
+    database: &PgPool,
+    guild_id: GuildId,
+    user_ids: impl IntoIterator<Item = UserId>,
+) -> Result<HashMap<UserId, String>> {
+    let user_ids = user_ids.into_iter().map(|user_id| user_id.get()).collect::<Vec<_>>();
+    let nicknames = database::nickname::fetch_by_user_ids(database, guild_id.get(), &user_ids)
+        .await
+        .with_context(|| format!("failed to fetch nicknames of {user_ids:?}"))?;
+
+    Ok(nicknames
+        .into_iter()
+        .map(|nickname| (UserId::new(nickname.user_id as u64), nickname.reading))
+        .collect())
+}